@@ -1,6 +1,7 @@
 use std::string::ToString;
 use leptos::prelude::*;
-use apexcharts_rs::prelude::{ApexChartComponent, ChartSeries, ChartType, SeriesData};
+use apexcharts_rs::prelude::{ChartSeries, ChartType, SeriesData};
+use apexcharts_rs::leptos::ApexChartComponent;
 
 #[component]
 fn App() -> impl IntoView {
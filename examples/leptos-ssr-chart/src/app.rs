@@ -1,7 +1,8 @@
 use leptos::*;
 use leptos_meta::{provide_meta_context, Title, Meta, Stylesheet};
 
-use apexcharts_rs::prelude::{ApexChartComponent, ChartSeries, ChartType, SeriesData};
+use apexcharts_rs::prelude::{ChartSeries, ChartType, SeriesData};
+use apexcharts_rs::leptos::ApexChartComponent;
 
 #[component]
 pub fn App() -> impl IntoView {
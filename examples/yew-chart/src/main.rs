@@ -1,5 +1,6 @@
 use yew::prelude::*;
-use apexcharts_rs::prelude::{ApexChartComponent, ChartSeries, SeriesData, ChartType};
+use apexcharts_rs::prelude::{ChartSeries, SeriesData, ChartType};
+use apexcharts_rs::yew::ApexChartComponent;
 
 #[function_component]
 pub fn App() -> Html {
@@ -0,0 +1,16 @@
+//! # ApexCharts Rust Fonts
+//!
+//! The DejaVu Sans fonts embedded by the `png` feature of `apexcharts-rs` to rasterize the charts independently of the
+//! fonts installed on the system. They are kept in this crate so that the other users of `apexcharts-rs` do not download
+//! them.
+
+#![no_std]
+
+/// The name of the font family of the fonts.
+pub const FONT_FAMILY: &str = "DejaVu Sans";
+
+/// The regular DejaVu Sans font.
+pub const DEJAVU_SANS: &[u8] = include_bytes!("../DejaVuSans.ttf");
+
+/// The bold DejaVu Sans font.
+pub const DEJAVU_SANS_BOLD: &[u8] = include_bytes!("../DejaVuSans-Bold.ttf");
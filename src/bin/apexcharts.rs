@@ -0,0 +1,392 @@
+//! The `apexcharts` command line tool, rendering charts from CSV or JSON data to standalone HTML pages or images.
+
+use std::fs;
+use std::io::{self, Read, Write};
+use std::process::ExitCode;
+use serde_json::{Map, Value};
+use apexcharts_rs::prelude::{ApexChartsError, ChartConfig, ChartSeries, ChartType, SeriesData};
+
+const USAGE: &str = "\
+Renders a chart from CSV or JSON data.
+
+Usage: apexcharts render [OPTIONS] <INPUT>
+
+The input is a CSV file with a header row, or a JSON array of objects. Use `-` to read it from the standard input.
+
+Options:
+  -t, --type <TYPE>        The type of the chart, eg. line, area, bar or pie [default: line]
+  -x, --x <COLUMN>         The column of the x values, or of the labels of pie and donut charts [default: the row numbers]
+  -y, --y <COLUMNS>        The comma separated columns of the series [default: the numeric columns]
+      --options <FILE>     A JSON file with the ApexCharts options
+      --title <TITLE>      The title of the chart
+      --width <WIDTH>      The width of the chart, eg. 800px [default: 100%]
+      --height <HEIGHT>    The height of the chart, eg. 400px [default: auto]
+  -o, --output <FILE>      The output file [default: the standard output]
+  -f, --format <FORMAT>    html, svg or png [default: the extension of the output file, or html]
+      --scale <FACTOR>     The scale of png images, eg. 2 for a 192 DPI image [default: 1]
+  -h, --help               Prints this help
+";
+
+/// The format of the rendered chart.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Format {
+	/// A standalone HTML page with the interactive chart.
+	Html,
+	/// A static SVG image, with the `static-render` feature.
+	Svg,
+	/// A static PNG image, with the `png` feature.
+	Png,
+}
+
+impl Format {
+	fn parse(name: &str) -> Result<Self, String> {
+		match name.to_ascii_lowercase().as_str() {
+			"html" | "htm" => Ok(Format::Html),
+			"svg" => Ok(Format::Svg),
+			"png" => Ok(Format::Png),
+			_ => Err(format!("unknown format `{}`, expected html, svg or png", name)),
+		}
+	}
+}
+
+/// The arguments of the `render` command.
+#[derive(Debug, Default, PartialEq)]
+struct RenderArgs {
+	input: String,
+	r#type: Option<ChartType>,
+	x: Option<String>,
+	y: Vec<String>,
+	options: Option<String>,
+	title: Option<String>,
+	width: Option<String>,
+	height: Option<String>,
+	output: Option<String>,
+	format: Option<Format>,
+	scale: Option<f32>,
+}
+
+/// The data read from a CSV or JSON file. The cells are kept as text, an empty cell being a missing value.
+#[derive(Debug, PartialEq)]
+struct Table {
+	columns: Vec<String>,
+	rows: Vec<Vec<String>>,
+}
+
+fn main() -> ExitCode {
+	match run(&std::env::args().skip(1).collect::<Vec<_>>()) {
+		Ok(()) => ExitCode::SUCCESS,
+		Err(error) => {
+			eprintln!("error: {}", error);
+			ExitCode::FAILURE
+		},
+	}
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+	if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+		print!("{}", USAGE);
+		return Ok(());
+	}
+	match args.first().map(String::as_str) {
+		Some("render") => render(&parse_args(&args[1..])?),
+		Some(command) => Err(format!("unknown command `{}`\n\n{}", command, USAGE)),
+		None => Err(format!("missing command\n\n{}", USAGE)),
+	}
+}
+
+/// Parses the arguments of the `render` command. The options accept their value either as the next argument or
+/// after an `=`, eg. `--type=bar`.
+fn parse_args(args: &[String]) -> Result<RenderArgs, String> {
+	let mut parsed = RenderArgs::default();
+	let mut input = None;
+	let mut args = args.iter();
+	while let Some(arg) = args.next() {
+		let (name, inline) = match arg.split_once('=') {
+			Some((name, value)) if name.starts_with("--") => (name, Some(value.to_string())),
+			_ => (arg.as_str(), None),
+		};
+		let mut value = || inline.clone()
+			.or_else(|| args.next().cloned())
+			.ok_or_else(|| format!("missing value for `{}`", name));
+		match name {
+			"-t" | "--type" => parsed.r#type = Some(value()?.parse().map_err(|error: ApexChartsError| error.to_string())?),
+			"-x" | "--x" => parsed.x = Some(value()?),
+			"-y" | "--y" => parsed.y.extend(value()?.split(',').map(|column| column.trim().to_string()).filter(|column| !column.is_empty())),
+			"--options" => parsed.options = Some(value()?),
+			"--title" => parsed.title = Some(value()?),
+			"--width" => parsed.width = Some(value()?),
+			"--height" => parsed.height = Some(value()?),
+			"-o" | "--output" => parsed.output = Some(value()?),
+			"-f" | "--format" => parsed.format = Some(Format::parse(&value()?)?),
+			"--scale" => {
+				let scale = value()?;
+				parsed.scale = Some(scale.parse().map_err(|_| format!("invalid scale `{}`", scale))?);
+			},
+			name if name.starts_with('-') && name != "-" => return Err(format!("unknown option `{}`", name)),
+			_ if input.is_some() => return Err(format!("unexpected argument `{}`", arg)),
+			_ => input = Some(arg.clone()),
+		}
+	}
+	parsed.input = input.ok_or("missing input file")?;
+	Ok(parsed)
+}
+
+/// Renders the chart of the input and writes it to the output.
+fn render(args: &RenderArgs) -> Result<(), String> {
+	let data = if args.input == "-" {
+		let mut data = String::new();
+		io::stdin().read_to_string(&mut data).map_err(|error| format!("cannot read the standard input: {}", error))?;
+		data
+	} else {
+		fs::read_to_string(&args.input).map_err(|error| format!("cannot read `{}`: {}", args.input, error))?
+	};
+	let table = read_table(&data)?;
+	let chart_type = args.r#type.clone().unwrap_or(ChartType::Line);
+	let series = series_from_table(&table, &chart_type, args.x.as_deref(), &args.y)?;
+	let config = chart_config(args, chart_type, series)?;
+	let format = args.format
+		.or_else(|| args.output.as_deref()
+			.and_then(|output| output.rsplit_once('.'))
+			.and_then(|(_, extension)| Format::parse(extension).ok()))
+		.unwrap_or(Format::Html);
+	let rendered = render_format(&config, format, args.scale).map_err(|error| error.to_string())?;
+	match &args.output {
+		Some(output) => fs::write(output, rendered).map_err(|error| format!("cannot write `{}`: {}", output, error)),
+		None => match io::stdout().write_all(&rendered) {
+			// The output was piped into a command which exited early, eg. `head`.
+			Err(error) if error.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+			result => result.map_err(|error| format!("cannot write the standard output: {}", error)),
+		},
+	}
+}
+
+/// Renders the chart in the format.
+fn render_format(config: &ChartConfig, format: Format, scale: Option<f32>) -> Result<Vec<u8>, ApexChartsError> {
+	match format {
+		Format::Html => config.to_standalone_html().map(String::into_bytes),
+		#[cfg(feature = "static-render")]
+		Format::Svg => config.to_svg().map(String::into_bytes),
+		#[cfg(not(feature = "static-render"))]
+		Format::Svg => Err(ApexChartsError::Unsupported("svg output requires the `static-render` feature".to_string())),
+		#[cfg(feature = "png")]
+		Format::Png => config.to_png(scale, None),
+		#[cfg(not(feature = "png"))]
+		Format::Png => {
+			let _ = scale;
+			Err(ApexChartsError::Unsupported("png output requires the `png` feature".to_string()))
+		},
+	}
+}
+
+/// Reads the table from a JSON array of objects, or from CSV with a header row.
+fn read_table(data: &str) -> Result<Table, String> {
+	if data.trim_start().starts_with('[') {
+		table_from_json(data)
+	} else {
+		table_from_csv(data)
+	}
+}
+
+fn table_from_csv(data: &str) -> Result<Table, String> {
+	let mut reader = csv::ReaderBuilder::new().trim(csv::Trim::All).from_reader(data.as_bytes());
+	let columns = reader.headers()
+		.map_err(|error| format!("invalid CSV: {}", error))?
+		.iter()
+		.map(str::to_string)
+		.collect();
+	let rows = reader.records()
+		.map(|record| record.map(|record| record.iter().map(str::to_string).collect()))
+		.collect::<Result<_, _>>()
+		.map_err(|error| format!("invalid CSV: {}", error))?;
+	Ok(Table { columns, rows })
+}
+
+/// Reads the table from a JSON array of objects. The columns are the keys of the objects.
+fn table_from_json(data: &str) -> Result<Table, String> {
+	let records = serde_json::from_str::<Vec<Map<String, Value>>>(data)
+		.map_err(|error| format!("invalid JSON, expected an array of objects: {}", error))?;
+	let mut columns = Vec::<String>::new();
+	for key in records.iter().flat_map(Map::keys) {
+		if !columns.contains(key) {
+			columns.push(key.clone());
+		}
+	}
+	let rows = records.iter()
+		.map(|record| columns.iter().map(|column| match record.get(column) {
+			None | Some(Value::Null) => Ok(String::new()),
+			Some(Value::String(value)) => Ok(value.clone()),
+			Some(value @ (Value::Number(_) | Value::Bool(_))) => Ok(value.to_string()),
+			Some(value) => Err(format!("unsupported value `{}` in column `{}`", value, column)),
+		}).collect())
+		.collect::<Result<_, _>>()?;
+	Ok(Table { columns, rows })
+}
+
+/// Maps the columns of the table to the series of the chart, one series for each of the `y` columns.
+///
+/// The series use the integer data of the crate when all their values are integers. Otherwise, as well as for the
+/// radial charts, they use [SeriesData::Radial] pairs, which ApexCharts reads as the `x` and `y` values of the points
+/// for any type of chart.
+fn series_from_table(table: &Table, chart_type: &ChartType, x: Option<&str>, y: &[String]) -> Result<Vec<ChartSeries>, String> {
+	let column = |name: &str| table.columns.iter()
+		.position(|column| column == name)
+		.ok_or_else(|| format!("unknown column `{}`, the columns are: {}", name, table.columns.join(", ")));
+	let x = x.map(column).transpose()?;
+	let y = if y.is_empty() {
+		(0..table.columns.len())
+			.filter(|index| Some(*index) != x)
+			.filter(|index| table.rows.iter().any(|row| !row[*index].is_empty()))
+			.filter(|index| table.rows.iter().all(|row| row[*index].is_empty() || row[*index].parse::<f64>().is_ok()))
+			.collect::<Vec<_>>()
+	} else {
+		y.iter().map(|name| column(name)).collect::<Result<_, _>>()?
+	};
+	if y.is_empty() {
+		return Err("no numeric column to chart, select the columns with `--y`".to_string());
+	}
+	let radial = matches!(chart_type, ChartType::Pie | ChartType::Donut | ChartType::RadialBar);
+
+	y.into_iter().map(|index| {
+		let name = &table.columns[index];
+		let mut points = Vec::new();
+		for (row_index, row) in table.rows.iter().enumerate() {
+			if row[index].is_empty() {
+				continue;
+			}
+			let value = row[index].parse::<f64>()
+				.ok()
+				.filter(|value| value.is_finite())
+				.ok_or_else(|| format!("invalid number `{}` in column `{}` of row {}", row[index], name, row_index + 1))?;
+			let label = match x {
+				Some(x) => row[x].clone(),
+				None => (row_index + 1).to_string(),
+			};
+			points.push((label, value));
+		}
+		let integers = points.iter().all(|(_, value)| value.fract() == 0.0 && value.abs() < 9e15);
+		let data = if radial || !integers {
+			SeriesData::Radial(points)
+		} else if x.is_none() && points.len() == table.rows.len() {
+			SeriesData::Single(points.into_iter().map(|(_, value)| value as i64).collect())
+		} else if points.iter().all(|(label, _)| label.parse::<i64>().is_ok()) {
+			SeriesData::NumericPaired(points.into_iter().map(|(label, value)| (label.parse().unwrap_or_default(), value as i64)).collect())
+		} else {
+			SeriesData::CategoryPaired(points.into_iter().map(|(label, value)| (label, value as i64)).collect())
+		};
+		Ok(ChartSeries {
+			name: name.clone(),
+			data,
+			color: String::new(),
+			r#type: None,
+			z_index: None,
+		})
+	}).collect()
+}
+
+/// Builds the configuration of the chart from the arguments, merging the title into the options file.
+fn chart_config(args: &RenderArgs, chart_type: ChartType, series: Vec<ChartSeries>) -> Result<ChartConfig, String> {
+	let mut options = match &args.options {
+		Some(path) => {
+			let options = fs::read_to_string(path).map_err(|error| format!("cannot read `{}`: {}", path, error))?;
+			serde_json::from_str::<Value>(&options).map_err(|error| format!("invalid options in `{}`: {}", path, error))?
+		},
+		None => Value::Object(Map::new()),
+	};
+	if let Some(title) = &args.title {
+		let Some(options) = options.as_object_mut() else {
+			return Err("the options must be a JSON object".to_string());
+		};
+		let title_options = options.entry("title").or_insert_with(|| Value::Object(Map::new()));
+		let Some(title_options) = title_options.as_object_mut() else {
+			return Err("the `title` option must be a JSON object".to_string());
+		};
+		title_options.insert("text".to_string(), Value::String(title.clone()));
+	}
+	let defaults = ChartConfig::new(chart_type, series);
+	Ok(ChartConfig {
+		options: options.to_string(),
+		width: args.width.clone().unwrap_or(defaults.width.clone()),
+		height: args.height.clone().unwrap_or(defaults.height.clone()),
+		..defaults
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use apexcharts_rs::prelude::{ChartType, SeriesData};
+	use super::{chart_config, parse_args, read_table, series_from_table, Format, RenderArgs};
+
+	fn args(args: &str) -> Vec<String> {
+		args.split_whitespace().map(str::to_string).collect()
+	}
+
+	#[test]
+	pub fn test_parse_args() {
+		let parsed = parse_args(&args("--type line -x date --y p95,p99 data.csv -o chart.html --title=Latency")).unwrap();
+		assert_eq!(parsed, RenderArgs {
+			input: "data.csv".to_string(),
+			r#type: Some(ChartType::Line),
+			x: Some("date".to_string()),
+			y: vec!["p95".to_string(), "p99".to_string()],
+			title: Some("Latency".to_string()),
+			output: Some("chart.html".to_string()),
+			..RenderArgs::default()
+		});
+		assert_eq!(parse_args(&args("- -f SVG --scale 2")).unwrap().format, Some(Format::Svg));
+		assert!(parse_args(&args("--type lines data.csv")).is_err());
+		assert!(parse_args(&args("data.csv --unknown")).is_err());
+		assert!(parse_args(&args("data.csv other.csv")).is_err());
+		assert!(parse_args(&args("--type")).is_err());
+		assert!(parse_args(&args("--type bar")).is_err());
+	}
+
+	#[test]
+	pub fn test_read_table() {
+		let csv = read_table("date, p95, p99\n2024-01-01, 120, 250\n2024-01-02, 130.5,\n").unwrap();
+		assert_eq!(csv.columns, vec!["date", "p95", "p99"]);
+		assert_eq!(csv.rows[1], vec!["2024-01-02", "130.5", ""]);
+
+		// The order of the keys depends on whether serde_json preserves it, so the cells are compared by column.
+		let json = read_table(r#"[{"host": "a", "load": 1}, {"host": "b", "cpu": 0.5}]"#).unwrap();
+		let cells = |column: &str| {
+			let index = json.columns.iter().position(|name| name == column).unwrap();
+			json.rows.iter().map(|row| row[index].as_str()).collect::<Vec<_>>()
+		};
+		assert_eq!(json.columns.len(), 3);
+		assert_eq!((cells("host"), cells("load"), cells("cpu")), (vec!["a", "b"], vec!["1", ""], vec!["", "0.5"]));
+
+		assert!(read_table(r#"[{"host": ["a"]}]"#).is_err());
+		assert!(read_table("a,b\n1,2,3\n").is_err());
+	}
+
+	#[test]
+	pub fn test_series_from_table() {
+		let table = read_table("date,p95,p99,host\n2024-01-01,120,250,a\n2024-01-02,130.5,,b\n").unwrap();
+		let series = series_from_table(&table, &ChartType::Line, Some("date"), &[]).unwrap();
+		assert_eq!(series.iter().map(|series| series.name.as_str()).collect::<Vec<_>>(), vec!["p95", "p99"]);
+		assert_eq!(series[0].data, SeriesData::Radial(vec![("2024-01-01".to_string(), 120.0), ("2024-01-02".to_string(), 130.5)]));
+		assert_eq!(series[1].data, SeriesData::CategoryPaired(vec![("2024-01-01".to_string(), 250)]));
+
+		let series = series_from_table(&table, &ChartType::Bar, None, &["p99".to_string()]).unwrap();
+		assert_eq!(series[0].data, SeriesData::NumericPaired(vec![(1, 250)]));
+
+		let table = read_table("minute,requests\n1,10\n2,20\n").unwrap();
+		let series = series_from_table(&table, &ChartType::Area, None, &["requests".to_string()]).unwrap();
+		assert_eq!(series[0].data, SeriesData::Single(vec![10, 20]));
+		let series = series_from_table(&table, &ChartType::Pie, Some("minute"), &["requests".to_string()]).unwrap();
+		assert_eq!(series[0].data, SeriesData::Radial(vec![("1".to_string(), 10.0), ("2".to_string(), 20.0)]));
+
+		assert!(series_from_table(&table, &ChartType::Line, Some("hour"), &[]).is_err());
+		assert!(series_from_table(&table, &ChartType::Line, None, &["host".to_string()]).is_err());
+		let table = read_table("host\na\n").unwrap();
+		assert!(series_from_table(&table, &ChartType::Line, None, &[]).is_err());
+	}
+
+	#[test]
+	pub fn test_chart_config() {
+		let parsed = parse_args(&args("data.csv --title Latency --height 300px")).unwrap();
+		let config = chart_config(&parsed, ChartType::Bar, vec![]).unwrap();
+		assert_eq!(config.options, r#"{"title":{"text":"Latency"}}"#);
+		assert_eq!((config.width.as_str(), config.height.as_str()), ("100%", "300px"));
+	}
+}
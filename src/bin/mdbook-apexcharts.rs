@@ -0,0 +1,173 @@
+//! The `mdbook-apexcharts` mdBook preprocessor, rendering the `apexchart` code blocks of the chapters into charts.
+//!
+//! Add it to the `book.toml` of the book:
+//!
+//! ```toml
+//! [preprocessor.apexcharts]
+//! ```
+//!
+//! mdBook runs the preprocessor with the book in JSON on the standard input, and reads the preprocessed book from its
+//! standard output. The book is handled as plain JSON so that the preprocessor works with the versions of mdBook which
+//! share this format.
+//!
+//! The ApexCharts library is written once to `apexcharts.js` in the source directory of the book, which mdBook copies
+//! to the output, and loaded from there by the chapters containing charts. It cannot be written to the output directly,
+//! as mdBook clears the output directory after running the preprocessors. The file is generated, and should be added
+//! to the `.gitignore` of the book rather than committed.
+
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Component, Path};
+use std::process::ExitCode;
+use serde_json::Value;
+use apexcharts_rs::prelude::{library_module, render_chart_blocks_with, ChartBlockOptions};
+
+/// The file of the ApexCharts library in the source directory of the book.
+const LIBRARY_FILE: &str = "apexcharts.js";
+
+fn main() -> ExitCode {
+	let args = std::env::args().skip(1).collect::<Vec<_>>();
+	match args.first().map(String::as_str) {
+		// The charts are only rendered in HTML.
+		Some("supports") => match args.get(1).map(String::as_str) {
+			Some("html") => ExitCode::SUCCESS,
+			_ => ExitCode::FAILURE,
+		},
+		_ => {
+			let mut input = String::new();
+			let result = io::stdin().read_to_string(&mut input)
+				.map_err(|error| format!("cannot read the book: {}", error))
+				.and_then(|_| preprocess(&input));
+			match result {
+				Ok(book) => {
+					print!("{}", book);
+					ExitCode::SUCCESS
+				},
+				Err(error) => {
+					eprintln!("mdbook-apexcharts: {}", error);
+					ExitCode::FAILURE
+				},
+			}
+		},
+	}
+}
+
+/// Renders the charts of the chapters of the book, from the `[context, book]` input of mdBook, and writes the library
+/// to the source directory when the book contains charts.
+fn preprocess(input: &str) -> Result<String, String> {
+	let (context, mut book) = serde_json::from_str::<(Value, Value)>(input)
+		.map_err(|error| format!("invalid input from mdBook: {}", error))?;
+	let mut has_charts = false;
+	// The chapters are listed in `sections` up to mdBook 0.4, and in `items` afterwards.
+	for key in ["sections", "items"] {
+		if let Some(items) = book.get_mut(key) {
+			has_charts |= render_items(items)?;
+		}
+	}
+	if has_charts {
+		let root = context.get("root").and_then(Value::as_str).unwrap_or(".");
+		let src = context.pointer("/config/book/src").and_then(Value::as_str).unwrap_or("src");
+		write_library(&Path::new(root).join(src).join(LIBRARY_FILE))?;
+	}
+	Ok(book.to_string())
+}
+
+/// Writes the library to the file, unless it is already up to date so that `mdbook serve` does not rebuild the book.
+fn write_library(path: &Path) -> Result<(), String> {
+	let library = library_module();
+	if fs::read_to_string(path).is_ok_and(|current| current == library) {
+		return Ok(());
+	}
+	fs::write(path, library).map_err(|error| format!("cannot write the ApexCharts library to {}: {}", path.display(), error))
+}
+
+/// Returns the options rendering the charts of the chapter at the path, relative to the source directory. The ids are
+/// prefixed with the path so that they are unique in the book, and the library is loaded relatively to the chapter.
+fn chapter_options(path: &str) -> ChartBlockOptions {
+	let slug = path.chars().map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '-' }).collect::<String>();
+	let depth = Path::new(path).components().filter(|component| matches!(component, Component::Normal(_))).count();
+	ChartBlockOptions {
+		id_prefix: format!("apexchart-{}", slug),
+		library_url: Some(format!("{}{}", "../".repeat(depth.saturating_sub(1)), LIBRARY_FILE)),
+	}
+}
+
+/// Renders the charts of the chapters among the items, and of their sub chapters. Returns whether any chart was rendered.
+fn render_items(items: &mut Value) -> Result<bool, String> {
+	let mut has_charts = false;
+	for item in items.as_array_mut().into_iter().flatten() {
+		let Some(chapter) = item.get_mut("Chapter").and_then(Value::as_object_mut) else {
+			continue;
+		};
+		let name = chapter.get("path")
+			.or_else(|| chapter.get("name"))
+			.and_then(Value::as_str)
+			.unwrap_or_default()
+			.to_string();
+		if let Some(Value::String(content)) = chapter.get_mut("content") {
+			let rendered = render_chart_blocks_with(content, &chapter_options(&name)).map_err(|error| format!("{}: {}", name, error))?;
+			has_charts |= rendered != *content;
+			*content = rendered;
+		}
+		if let Some(sub_items) = chapter.get_mut("sub_items") {
+			has_charts |= render_items(sub_items)?;
+		}
+	}
+	Ok(has_charts)
+}
+
+#[cfg(test)]
+mod tests {
+	use std::fs;
+	use serde_json::{json, Value};
+	use apexcharts_rs::prelude::library_module;
+	use super::preprocess;
+
+	fn chapter(path: &str, content: &str, sub_items: Value) -> Value {
+		json!({"Chapter": {"name": path, "content": content, "number": [1], "sub_items": sub_items, "path": path, "source_path": path, "parent_names": []}})
+	}
+
+	#[test]
+	pub fn test_preprocess() {
+		let root = std::env::temp_dir().join(format!("mdbook-apexcharts-{}", std::process::id()));
+		fs::create_dir_all(root.join("book")).unwrap();
+		let context = json!({"root": root, "renderer": "html", "config": {"book": {"src": "book"}}});
+		let chart = "# Sales\n\n```apexchart\n{\"type\": \"bar\", \"series\": [{\"name\": \"Sales\", \"data\": {\"Single\": [1, 2]}}]}\n```\n";
+		let book = json!({
+			"sections": [
+				chapter("intro.md", "# Intro\n", json!([chapter("reports/sales.md", chart, json!([]))])),
+				"Separator",
+				{"PartTitle": "Appendix"},
+			],
+			"__non_exhaustive": null
+		});
+		let output = preprocess(&json!([context, book]).to_string()).unwrap();
+		let output = serde_json::from_str::<Value>(&output).unwrap();
+		assert_eq!(output["sections"][0]["Chapter"]["content"], "# Intro\n");
+		let content = output["sections"][0]["Chapter"]["sub_items"][0]["Chapter"]["content"].as_str().unwrap();
+		// The library is loaded from the file written once for the book, relatively to the chapter.
+		assert!(content.starts_with("# Sales\n\n<script type=\"module\" src=\"../apexcharts.js\"></script>\n\n"));
+		assert!(content.contains("<div id=\"apexchart-reports-sales-md-1\"></div>"));
+		assert!(!content.contains("ApexCharts v3.40.0"));
+		assert_eq!(output["sections"][1], "Separator");
+		assert_eq!(fs::read_to_string(root.join("book/apexcharts.js")).unwrap(), library_module());
+
+		let items = json!({"items": [chapter("sales.md", chart, json!([]))]});
+		let output = preprocess(&json!([context, items]).to_string()).unwrap();
+		assert!(output.contains("apexchart-sales-md-1") && output.contains("src=\\\"apexcharts.js\\\""));
+		fs::remove_dir_all(&root).unwrap();
+
+		// The library is not written for a book without charts.
+		let book = json!({"items": [chapter("intro.md", "# Intro\n", json!([]))]});
+		assert!(preprocess(&json!([context, book]).to_string()).is_ok());
+		assert!(!root.exists());
+	}
+
+	#[test]
+	pub fn test_preprocess_errors() {
+		let book = json!({"sections": [chapter("sales.md", "```apexchart\n{\"type\": \"bars\"}\n```\n", json!([]))]});
+		let error = preprocess(&json!([{}, book]).to_string()).unwrap_err();
+		assert!(error.starts_with("sales.md: Invalid chart options: invalid `apexchart` block at line 1:"));
+		assert!(preprocess("{}").is_err());
+	}
+}
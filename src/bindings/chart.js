@@ -33164,7 +33164,10 @@ export class ApexChart {
     }
 
     render(element_id) {
-        const element = document.getElementById(element_id);
+        this.render_into(document.getElementById(element_id));
+    }
+
+    render_into(element) {
         this.chart = new ApexCharts(element, this.options);
         this.chart.render();
     }
@@ -33180,7 +33183,7 @@ export class ApexChart {
         if (this.chart === undefined) {
             return;
         }
-        this.chart.appendData(series, animate);
+        this.chart.appendSeries(series, animate);
     }
 
     toggle_series(series_name) {
@@ -33240,11 +33243,66 @@ export class ApexChart {
     }
 
     destroy() {
+        this.destroyed = true;
         if (this.chart === undefined) {
             return;
         }
-        this.chart.destroy();
+        const chart = this.chart;
+        this.chart = undefined;
+        chart.destroy();
+    }
+
+    is_destroyed() {
+        return this.destroyed === true;
     }
 }
 
+function is_options_script(node) {
+    return node.localName === "script" && node.type === "application/json";
+}
+
+export function define_chart_element(name, observed_attributes, connected, disconnected, options_changed) {
+    if (customElements.get(name) !== undefined) {
+        return;
+    }
+    customElements.define(name, class extends HTMLElement {
+        static get observedAttributes() {
+            return observed_attributes;
+        }
+
+        connectedCallback() {
+            if (this.style.display === "") {
+                this.style.display = "block";
+            }
+            // The json script may be parsed after the element is connected, or be edited afterwards.
+            this.observer = new MutationObserver((mutations) => {
+                const changed = mutations.some((mutation) => {
+                    if (mutation.target === this) {
+                        return [...mutation.addedNodes, ...mutation.removedNodes].some(is_options_script);
+                    }
+                    const script = mutation.target.nodeType === Node.TEXT_NODE ? mutation.target.parentNode : mutation.target;
+                    return script !== null && script.parentNode === this && is_options_script(script);
+                });
+                if (changed) {
+                    options_changed(this);
+                }
+            });
+            this.observer.observe(this, { childList: true, subtree: true, characterData: true });
+            connected(this);
+        }
+
+        disconnectedCallback() {
+            if (this.observer !== undefined) {
+                this.observer.disconnect();
+                this.observer = undefined;
+            }
+            disconnected(this);
+        }
 
+        attributeChangedCallback(name, old_value, new_value) {
+            if (old_value !== new_value) {
+                options_changed(this);
+            }
+        }
+    });
+}
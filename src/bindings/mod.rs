@@ -6,19 +6,42 @@ extern "C" {
 	/// Once created using the `new()` method, the chart should be rendered on the page using the 
 	/// `render()` method before performing any other operations. Failure to do so will result in
 	/// the subsequent operations not doing anything.
+	#[derive(Clone, Debug, PartialEq)]
 	pub type ApexChart;
 
 	/// Create a new instance of the `ApexChart` type.
 	#[wasm_bindgen(constructor)]
 	pub fn new(options: &JsValue) -> ApexChart;
 
+	/// Create a new instance of the `ApexChart` type, returning the exception thrown by the library
+	/// instead of aborting when the options are not valid.
+	#[wasm_bindgen(constructor, catch)]
+	pub fn try_new(options: &JsValue) -> Result<ApexChart, JsValue>;
+
 	/// The `render()` method is responsible for drawing the bindings on the page.
 	/// It is the primary method that has to be called after configuring the options.
 	///
-	/// The `element_id` is the id of the element in which the bindings will be drawn.
+	/// The `element_id` is the id of the element in which the bindings will be drawn. The element is looked up
+	/// with `document.getElementById`, use `render_into()` to draw the bindings in an element you already have.
 	#[wasm_bindgen(method)]
 	pub fn render(this: &ApexChart, element_id: &str);
 
+	/// The fallible version of the `render()` method. Returns the exception thrown by the library
+	/// when the bindings could not be drawn on the page.
+	#[wasm_bindgen(method, catch, js_name = render)]
+	pub fn try_render(this: &ApexChart, element_id: &str) -> Result<(), JsValue>;
+
+	/// The `render_into()` method draws the bindings inside the given element. Unlike `render()`, it does not
+	/// look up the element in the document, so it can draw charts inside a Shadow DOM or an iframe and does not
+	/// require the element to have a unique id.
+	#[wasm_bindgen(method)]
+	pub fn render_into(this: &ApexChart, element: &web_sys::HtmlElement);
+
+	/// The fallible version of the `render_into()` method. Returns the exception thrown by the library
+	/// when the bindings could not be drawn in the element.
+	#[wasm_bindgen(method, catch, js_name = render_into)]
+	pub fn try_render_into(this: &ApexChart, element: &web_sys::HtmlElement) -> Result<(), JsValue>;
+
 	/// This method allows you to update the configuration object by passing the options as the first parameter. The new config object is merged with the existing config object preserving the existing configuration.
 	///
 	/// The `redraw` parameter is a boolean value that allows you to redraw the bindings after updating the configuration. Default value is `false`.
@@ -56,6 +79,10 @@ extern "C" {
 	#[wasm_bindgen(method)]
 	pub fn zoom_x(this: &ApexChart, min: u64, max: u64);
 
+	/// Manually zoom into the bindings with the start and end X values as JavaScript numbers.
+	#[wasm_bindgen(method, js_name = zoom_x)]
+	pub(crate) fn zoom_x_f64(this: &ApexChart, min: f64, max: f64);
+
 	/// This method allows you to append new data to the series array. If you have existing multiple series, provide the new array in the same indexed order.
 	#[wasm_bindgen(method)]
 	pub fn append_data(this: &ApexChart, data: &JsValue);
@@ -68,4 +95,26 @@ extern "C" {
 	#[wasm_bindgen(method)]
 	pub fn destroy(this: &ApexChart);
 
+	/// Returns whether the bindings have been destroyed with the `destroy()` method. The other methods do nothing once
+	/// the bindings have been destroyed.
+	#[wasm_bindgen(method)]
+	pub fn is_destroyed(this: &ApexChart) -> bool;
+
+	/// The fallible version of the `destroy()` method. Returns the exception thrown by the library when the bindings
+	/// could not be destroyed, eg. when they were not completely drawn.
+	#[wasm_bindgen(method, catch, js_name = destroy)]
+	pub fn try_destroy(this: &ApexChart) -> Result<(), JsValue>;
+
+	/// Defines a custom element calling the callbacks with the element when it is added to the page, removed from
+	/// the page and when one of the observed attributes or its child json script changes. Does nothing if the element
+	/// is already defined.
+	#[cfg(feature = "custom-element")]
+	pub(crate) fn define_chart_element(
+		name: &str,
+		observed_attributes: &wasm_bindgen_futures::js_sys::Array,
+		connected: &Closure<dyn FnMut(web_sys::HtmlElement)>,
+		disconnected: &Closure<dyn FnMut(web_sys::HtmlElement)>,
+		options_changed: &Closure<dyn FnMut(web_sys::HtmlElement)>,
+	);
+
 }
@@ -0,0 +1,223 @@
+//! This module contains the framework independent assembly of the options passed to ApexCharts.
+
+use serde_json::{Map, Value};
+use crate::error::ApexChartsError;
+use crate::options::{ChartSeries, ChartType, SeriesData};
+
+/// Represents the configuration of a chart.
+///
+/// This type combines the type, series, options and size of a chart into the final options object that
+/// is passed to ApexCharts. It is used by all the chart components so that they render the same chart for
+/// the same configuration. It does not depend on the browser and can be used on the server.
+///
+/// # Example
+///
+/// ```rust
+/// use apexcharts_rs::prelude::{ChartConfig, ChartSeries, ChartType, SeriesData};
+///
+/// let config = ChartConfig {
+///     options: r#"{"legend": {"show": false}}"#.to_string(),
+///     height: "300px".to_string(),
+///     ..ChartConfig::new(ChartType::Pie, vec![
+///         ChartSeries {
+///             name: "Expenses".to_string(),
+///             data: SeriesData::Radial(vec![("Rent".to_string(), 60.0), ("Food".to_string(), 40.0)]),
+///             color: "#1A56DB".to_string(),
+///             r#type: None,
+///             z_index: None,
+///         }
+///     ])
+/// };
+/// let options = config.to_value().unwrap();
+/// assert_eq!(options["labels"], serde_json::json!(["Rent", "Food"]));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChartConfig {
+	/// The id of the chart. It is set as the `chart.id` option so that the chart can be retrieved
+	/// with `ApexCharts.exec`.
+	pub id: Option<String>,
+	/// The type of the chart.
+	pub r#type: ChartType,
+	/// The series to be rendered in the chart.
+	pub series: Vec<ChartSeries>,
+	/// The options for the chart in json. An empty string is treated as no options. Refer to the
+	/// [ApexCharts documentation](https://apexcharts.com/docs/options/) for more information.
+	pub options: String,
+	/// The width of the chart.
+	pub width: String,
+	/// The height of the chart.
+	pub height: String,
+	/// The text displayed by ApexCharts in place of the chart when the series are empty. It is set as
+	/// the `noData.text` option.
+	pub no_data_text: Option<String>,
+}
+
+impl ChartConfig {
+	/// Creates a new configuration without an id, with no options, no `noData` text, a width of `100%` and an `auto` height.
+	pub fn new(r#type: ChartType, series: Vec<ChartSeries>) -> Self {
+		Self {
+			id: None,
+			r#type,
+			series,
+			options: String::new(),
+			width: "100%".to_string(),
+			height: "auto".to_string(),
+			no_data_text: None,
+		}
+	}
+
+	/// Builds the options object passed to ApexCharts.
+	///
+	/// The user options are merged with the id, type, size and series of the configuration, which take
+	/// precedence. For the `Pie`, `Donut` and `RadialBar` charts the [SeriesData::Radial] data of the first
+	/// series is split into the values and the `labels` of the chart.
+	pub fn to_value(&self) -> Result<Value, ApexChartsError> {
+		let mut options = if self.options.trim().is_empty() {
+			Map::new()
+		} else {
+			match serde_json::from_str::<Value>(&self.options) {
+				Ok(Value::Object(options)) => options,
+				Ok(_) => return Err(ApexChartsError::InvalidOptions("expected a JSON object".to_string())),
+				Err(error) => return Err(ApexChartsError::InvalidOptions(error.to_string())),
+			}
+		};
+		let chart = options.entry("chart").or_insert_with(|| Value::Object(Map::new()));
+		let Value::Object(chart) = chart else {
+			return Err(ApexChartsError::InvalidOptions("expected `chart` to be a JSON object".to_string()));
+		};
+		if let Some(id) = &self.id {
+			chart.insert("id".to_string(), Value::String(id.clone()));
+		}
+		chart.insert("type".to_string(), Value::String(self.r#type.to_string()));
+		chart.insert("width".to_string(), Value::String(self.width.clone()));
+		chart.insert("height".to_string(), Value::String(self.height.clone()));
+
+		if let Some(text) = &self.no_data_text {
+			let no_data = options.entry("noData").or_insert_with(|| Value::Object(Map::new()));
+			let Value::Object(no_data) = no_data else {
+				return Err(ApexChartsError::InvalidOptions("expected `noData` to be a JSON object".to_string()));
+			};
+			no_data.insert("text".to_string(), Value::String(text.clone()));
+		}
+
+		let (series, labels) = self.series_value()?;
+		options.insert("series".to_string(), series);
+		if let Some(labels) = labels {
+			options.insert("labels".to_string(), labels);
+		}
+		Ok(Value::Object(options))
+	}
+
+	/// Builds the options passed to ApexCharts serialized to a JSON string.
+	pub fn to_json(&self) -> Result<String, ApexChartsError> {
+		Ok(serde_json::to_string(&self.to_value()?)?)
+	}
+
+	/// Builds the options passed to ApexCharts as a JavaScript object.
+	#[cfg(feature = "wasm")]
+	pub fn to_jsvalue(&self) -> Result<wasm_bindgen::JsValue, ApexChartsError> {
+		use wasm_bindgen_futures::js_sys;
+
+		Ok(js_sys::JSON::parse(&self.to_json()?)?)
+	}
+
+	/// Returns whether the chart is one of the charts whose series is a single array of values
+	/// with the categories provided as labels.
+	pub fn is_radial(&self) -> bool {
+		matches!(self.r#type, ChartType::Pie | ChartType::Donut | ChartType::RadialBar)
+	}
+
+	/// Serializes the series of the chart, returning the labels of the chart if the series is radial.
+	pub(crate) fn series_value(&self) -> Result<(Value, Option<Value>), ApexChartsError> {
+		if !self.is_radial() {
+			return Ok((serde_json::to_value(&self.series)?, None));
+		}
+		match self.series.first() {
+			Some(ChartSeries { data: SeriesData::Radial(data), .. }) => {
+				let values = data.iter().map(|(_, y)| *y).collect::<Vec<_>>();
+				let labels = data.iter().map(|(x, _)| x.clone()).collect::<Vec<_>>();
+				if let Some(value) = values.iter().find(|value| !value.is_finite()) {
+					return Err(ApexChartsError::NonFiniteValue(*value));
+				}
+				Ok((serde_json::to_value(values)?, Some(serde_json::to_value(labels)?)))
+			},
+			Some(_) => Ok((serde_json::to_value(&self.series)?, None)),
+			None => Ok((Value::Array(vec![]), None)),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use serde_json::json;
+	use crate::prelude::{ApexChartsError, ChartConfig, ChartSeries, ChartType, SeriesData};
+
+	fn series(data: SeriesData) -> Vec<ChartSeries> {
+		vec![
+			ChartSeries {
+				name: "Series 1".to_string(),
+				data,
+				color: "#008FFB".to_string(),
+				r#type: None,
+				z_index: None,
+			}
+		]
+	}
+
+	#[test]
+	pub fn test_options_without_user_options() {
+		let config = ChartConfig::new(ChartType::Area, series(SeriesData::Single(vec![10, 20])));
+		assert_eq!(config.to_value().unwrap(), json!({
+			"chart": {"type": "area", "width": "100%", "height": "auto"},
+			"series": [{"name": "Series 1", "data": [10, 20], "color": "#008FFB"}]
+		}));
+	}
+
+	#[test]
+	pub fn test_options_merged_with_user_options() {
+		let config = ChartConfig {
+			id: Some("sales".to_string()),
+			options: r#"{"chart": {"type": "bar", "toolbar": {"show": false}}, "legend": {"show": false}}"#.to_string(),
+			width: r#"100"%"#.to_string(),
+			..ChartConfig::new(ChartType::Line, vec![])
+		};
+		assert_eq!(config.to_value().unwrap(), json!({
+			"chart": {"id": "sales", "type": "line", "width": "100\"%", "height": "auto", "toolbar": {"show": false}},
+			"legend": {"show": false},
+			"series": []
+		}));
+	}
+
+	#[test]
+	pub fn test_radial_options() {
+		let config = ChartConfig::new(ChartType::Donut, series(SeriesData::Radial(vec![("Rent".to_string(), 60.0), ("Food".to_string(), 40.0)])));
+		let options = config.to_value().unwrap();
+		assert_eq!(options["series"], json!([60.0, 40.0]));
+		assert_eq!(options["labels"], json!(["Rent", "Food"]));
+
+		let config = ChartConfig::new(ChartType::Pie, series(SeriesData::Radial(vec![("Rent".to_string(), f64::NAN)])));
+		assert!(matches!(config.to_value(), Err(ApexChartsError::NonFiniteValue(value)) if value.is_nan()));
+	}
+
+	#[test]
+	pub fn test_no_data_text() {
+		let config = ChartConfig {
+			options: r#"{"noData": {"align": "left"}}"#.to_string(),
+			no_data_text: Some("No sales yet".to_string()),
+			..ChartConfig::new(ChartType::Bar, vec![])
+		};
+		assert_eq!(config.to_value().unwrap()["noData"], json!({"align": "left", "text": "No sales yet"}));
+	}
+
+	#[test]
+	pub fn test_invalid_options() {
+		let invalid = ["{", "[1, 2]", r#"{"chart": 1}"#];
+		for options in invalid {
+			let config = ChartConfig {
+				options: options.to_string(),
+				..ChartConfig::new(ChartType::Line, vec![])
+			};
+			assert!(matches!(config.to_value(), Err(ApexChartsError::InvalidOptions(_))));
+		}
+	}
+}
@@ -0,0 +1,253 @@
+//! This module contains the `<apex-chart>` custom element for rendering ApexCharts without a Rust UI framework.
+
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use serde_json::Value;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::js_sys;
+use web_sys::{CustomEvent, CustomEventInit, HtmlElement};
+use crate::bindings::{define_chart_element, ApexChart};
+use crate::config::ChartConfig;
+use crate::error::ApexChartsError;
+use crate::handle::render_chart;
+use crate::options::ChartType;
+
+/// The name of the custom element.
+pub const CHART_ELEMENT_NAME: &str = "apex-chart";
+
+/// The attributes of the element that are read into the chart options. The chart is updated when any of them changes.
+const OBSERVED_ATTRIBUTES: [&str; 7] = ["id", "type", "series", "options", "width", "height", "no-data-text"];
+
+/// The name of the event dispatched on the element when the chart could not be rendered.
+const ERROR_EVENT: &str = "apex-chart-error";
+
+static REGISTERED: AtomicBool = AtomicBool::new(false);
+
+/// A chart rendered in an element with its type.
+type RenderedChart = (ApexChart, ChartType);
+
+thread_local! {
+	/// The connected elements with the chart rendered in each of them.
+	static ELEMENTS: RefCell<Vec<(HtmlElement, Option<RenderedChart>)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Registers the `<apex-chart>` custom element.
+///
+/// The element renders a chart from its attributes without any Rust UI framework, eg. in a server rendered page:
+///
+/// ```html
+/// <apex-chart type="bar" height="300px" series='[{"name": "Sales", "data": [10, 20, 30]}]'></apex-chart>
+/// ```
+///
+/// The `options` attribute contains the ApexCharts options in json. They can also be provided in a child
+/// `<script type="application/json">` element, which is easier to write for large options. The `series` attribute
+/// contains the series in the ApexCharts format and takes precedence over the series in the options, and so do the
+/// `id`, `type`, `width`, `height` and `no-data-text` attributes over the corresponding options.
+///
+/// The chart is updated when the attributes or the json script change, including when the script is parsed after the
+/// element has been added to the page, and destroyed when the element is removed from the page. When the chart cannot be
+/// rendered, an `apex-chart-error` event is dispatched on the element with the error message as its `detail`. The
+/// function is exported to JavaScript as `registerChartElement` and does nothing if the element is already registered.
+#[wasm_bindgen(js_name = registerChartElement)]
+pub fn register_chart_element() {
+	if REGISTERED.swap(true, Ordering::Relaxed) {
+		return;
+	}
+	let observed = OBSERVED_ATTRIBUTES.iter().map(|name| JsValue::from_str(name)).collect::<js_sys::Array>();
+	let connected = Closure::<dyn FnMut(HtmlElement)>::new(connect);
+	let disconnected = Closure::<dyn FnMut(HtmlElement)>::new(|element: HtmlElement| disconnect(&element));
+	let changed = Closure::<dyn FnMut(HtmlElement)>::new(|element: HtmlElement| {
+		if ELEMENTS.with(|elements| elements.borrow().iter().any(|(connected, _)| connected == &element)) {
+			draw(&element);
+		}
+	});
+	define_chart_element(CHART_ELEMENT_NAME, &observed, &connected, &disconnected, &changed);
+	// The element stays registered for the lifetime of the page.
+	connected.forget();
+	disconnected.forget();
+	changed.forget();
+}
+
+/// Renders the chart of an element that has been added to the page.
+fn connect(element: HtmlElement) {
+	ELEMENTS.with(|elements| elements.borrow_mut().push((element.clone(), None)));
+	draw(&element);
+}
+
+/// Destroys the chart of an element that has been removed from the page.
+fn disconnect(element: &HtmlElement) {
+	let removed = ELEMENTS.with(|elements| {
+		let mut elements = elements.borrow_mut();
+		let index = elements.iter().position(|(connected, _)| connected == element)?;
+		Some(elements.remove(index))
+	});
+	if let Some((_, Some((chart, _)))) = removed {
+		chart.destroy();
+	}
+}
+
+/// Renders the chart of the element from its attributes, updating the existing chart unless its type changed.
+/// The previous chart is destroyed when it is replaced or fails to update, and the element is left without a chart
+/// when the new chart cannot be rendered.
+fn draw(element: &HtmlElement) {
+	let mut current = take_chart(element);
+	let result = read_options(element).and_then(|(r#type, options)| {
+		let options = options.to_string();
+		if let Some((chart, current_type)) = &current {
+			if current_type == &r#type {
+				chart.update_options(&js_sys::JSON::parse(&options)?, None, None, None);
+				return Ok((chart.clone(), r#type));
+			}
+		}
+		if let Some((chart, _)) = current.take() {
+			chart.destroy();
+		}
+		Ok((render_chart(&options, element)?, r#type))
+	});
+	match result {
+		Ok(chart) => put_chart(element, chart),
+		Err(error) => {
+			if let Some((chart, _)) = current.take() {
+				chart.destroy();
+			}
+			report(element, &error);
+		},
+	}
+}
+
+/// Takes the chart rendered in the element, if any, so that it is not borrowed while calling into ApexCharts.
+fn take_chart(element: &HtmlElement) -> Option<RenderedChart> {
+	ELEMENTS.with(|elements| {
+		elements.borrow_mut().iter_mut()
+			.find(|(connected, _)| connected == element)
+			.and_then(|(_, chart)| chart.take())
+	})
+}
+
+/// Stores the chart rendered in the element if the element is still connected.
+fn put_chart(element: &HtmlElement, chart: RenderedChart) {
+	let stored = ELEMENTS.with(|elements| {
+		elements.borrow_mut().iter_mut()
+			.find(|(connected, _)| connected == element)
+			.map(|(_, stored)| *stored = Some(chart.clone()))
+	});
+	if stored.is_none() {
+		chart.0.destroy();
+	}
+}
+
+/// Reads the type and the options of the chart from the attributes and the json script of the element. The chart is
+/// drawn again when the script is added or edited later, eg. when the element is defined before its children are parsed.
+fn read_options(element: &HtmlElement) -> Result<(ChartType, Value), ApexChartsError> {
+	let script = element.query_selector(":scope > script[type=\"application/json\"]")
+		.ok()
+		.flatten()
+		.and_then(|script| script.text_content());
+	element_options(|name| element.get_attribute(name), script)
+}
+
+/// Reports the error in the console and dispatches it to the listeners of the element.
+fn report(element: &HtmlElement, error: &ApexChartsError) {
+	web_sys::console::error_1(&format!("apexcharts-rs: {}", error).into());
+	let init = CustomEventInit::new();
+	init.set_detail(&JsValue::from_str(&error.to_string()));
+	if let Ok(event) = CustomEvent::new_with_event_init_dict(ERROR_EVENT, &init) {
+		let _ = element.dispatch_event(&event);
+	}
+}
+
+/// Builds the type and the options of a chart from the attributes of an element and the contents of its json script.
+fn element_options(attribute: impl Fn(&str) -> Option<String>, script: Option<String>) -> Result<(ChartType, Value), ApexChartsError> {
+	let options = attribute("options").or(script).unwrap_or_default();
+	let user_options = serde_json::from_str::<Value>(&options).unwrap_or(Value::Null);
+	let r#type = match attribute("type") {
+		Some(name) => name.parse()?,
+		None => match user_options.pointer("/chart/type").and_then(Value::as_str) {
+			Some(name) => name.parse()?,
+			None => ChartType::Line,
+		},
+	};
+	let series = match attribute("series") {
+		Some(series) => serde_json::from_str::<Value>(&series)
+			.map_err(|error| ApexChartsError::InvalidOptions(format!("invalid `series` attribute: {}", error)))?,
+		None => user_options.get("series").cloned().unwrap_or(Value::Array(vec![])),
+	};
+	let width = attribute("width");
+	let height = attribute("height");
+	let defaults = ChartConfig::new(r#type.clone(), vec![]);
+	let config = ChartConfig {
+		id: attribute("id"),
+		options,
+		width: width.clone().unwrap_or(defaults.width.clone()),
+		height: height.clone().unwrap_or(defaults.height.clone()),
+		no_data_text: attribute("no-data-text"),
+		..defaults
+	};
+	let mut value = config.to_value()?;
+	let Some(options) = value.as_object_mut() else {
+		return Ok((r#type, value));
+	};
+	options.insert("series".to_string(), series);
+	// The size set in the options is kept unless it is overridden by an attribute.
+	if let Some(chart) = options.get_mut("chart").and_then(Value::as_object_mut) {
+		for (key, attribute) in [("width", width), ("height", height)] {
+			if let (None, Some(size)) = (attribute, user_options.pointer(&format!("/chart/{}", key))) {
+				chart.insert(key.to_string(), size.clone());
+			}
+		}
+	}
+	Ok((r#type, value))
+}
+
+#[cfg(test)]
+mod tests {
+	use std::collections::HashMap;
+	use serde_json::json;
+	use crate::prelude::{ApexChartsError, ChartType};
+	use super::element_options;
+
+	fn attributes(attributes: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+		let attributes = attributes.iter().map(|(name, value)| (name.to_string(), value.to_string())).collect::<HashMap<_, _>>();
+		move |name| attributes.get(name).cloned()
+	}
+
+	#[test]
+	pub fn test_options_from_attributes() {
+		let (r#type, options) = element_options(attributes(&[
+			("id", "sales"),
+			("type", "bar"),
+			("height", "300px"),
+			("series", r#"[{"name": "Sales", "data": [10, 20]}]"#),
+			("options", r#"{"legend": {"show": false}}"#),
+		]), None).unwrap();
+		assert_eq!(r#type, ChartType::Bar);
+		assert_eq!(options, json!({
+			"chart": {"id": "sales", "type": "bar", "width": "100%", "height": "300px"},
+			"legend": {"show": false},
+			"series": [{"name": "Sales", "data": [10, 20]}]
+		}));
+	}
+
+	#[test]
+	pub fn test_options_from_script() {
+		let script = r#"{"chart": {"type": "donut", "height": 350}, "series": [44, 55], "labels": ["A", "B"]}"#;
+		let (r#type, options) = element_options(attributes(&[]), Some(script.to_string())).unwrap();
+		assert_eq!(r#type, ChartType::Donut);
+		assert_eq!(options, json!({
+			"chart": {"type": "donut", "width": "100%", "height": 350},
+			"series": [44, 55],
+			"labels": ["A", "B"]
+		}));
+
+		let (r#type, options) = element_options(attributes(&[("type", "pie"), ("series", "[1, 2]")]), Some(script.to_string())).unwrap();
+		assert_eq!(r#type, ChartType::Pie);
+		assert_eq!(options["series"], json!([1, 2]));
+	}
+
+	#[test]
+	pub fn test_invalid_attributes() {
+		assert!(matches!(element_options(attributes(&[("type", "bars")]), None), Err(ApexChartsError::InvalidOptions(_))));
+		assert!(matches!(element_options(attributes(&[("series", "[1,")]), None), Err(ApexChartsError::InvalidOptions(_))));
+		assert!(matches!(element_options(attributes(&[("options", "[]")]), None), Err(ApexChartsError::InvalidOptions(_))));
+	}
+}
@@ -0,0 +1,203 @@
+//! This module contains the Dioxus component for rendering ApexCharts in a Dioxus web application.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use dioxus::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::HtmlElement;
+use crate::handle::draw;
+use crate::ids::{generate_id, register_id, unregister_id};
+use crate::prelude::{ApexChart, ApexChartsError, ChartConfig, ChartHandle, ChartSeries, ChartType};
+
+/// An ApexCharts component for Dioxus.
+///
+/// This component is used to render an ApexCharts chart in a Dioxus web application. It is used to render different types
+/// of charts such as line, bar, pie, donut, and radial bar charts. To use this component, you need to enable the `dioxus`
+/// feature in the `apexcharts-rs` crate.
+///
+/// The chart is rendered once the component is mounted and updated in place when its series or size change. It is
+/// recreated when its type, id, options or `no_data_text` change, and destroyed when the component is unmounted.
+///
+/// The series can also be loaded asynchronously by passing a `Resource` as the `data` property. A loading indicator
+/// is displayed until the resource resolves, and a retry button, restarting the resource, is displayed if it fails to load.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use dioxus::prelude::*;
+/// use apexcharts_rs::prelude::{ChartSeries, ChartType, SeriesData};
+/// use apexcharts_rs::dioxus::ApexChartComponent;
+///
+/// #[component]
+/// fn App() -> Element {
+///     let series = vec![
+///         ChartSeries {
+///             name: "Series 1".to_string(),
+///             data: SeriesData::Single(vec![10, 20, 30, 40, 50]),
+///             color: "#008FFB".to_string(),
+///             r#type: None,
+///             z_index: None,
+///         }
+///     ];
+///     rsx! {
+///         ApexChartComponent {
+///             id: "my_area_chart",
+///             r#type: ChartType::Area,
+///             series: series,
+///             height: "300px",
+///         }
+///     }
+/// }
+///
+/// fn main() {
+///     // The `web` feature of Dioxus is required to launch the application in the browser.
+/// #   #[cfg(any())]
+///     dioxus::launch(App);
+/// }
+/// ```
+#[component]
+pub fn ApexChartComponent(
+	/// The type of the chart. The chart is recreated when the type changes.
+	#[props(default = ChartType::Line)]
+	r#type: ChartType,
+	/// The options for the chart in json. The chart is recreated when they change.
+	#[props(into, default)]
+	options: String,
+	/// The unique id of the chart. It is also set as the `chart.id` option of ApexCharts. A unique id is
+	/// generated when it is not provided.
+	#[props(into)]
+	id: Option<String>,
+	/// The series to be rendered in the chart. It is ignored when a `data` resource is provided.
+	#[props(default)]
+	series: Vec<ChartSeries>,
+	/// Loads the series of the chart asynchronously. A loading indicator is displayed until the resource
+	/// resolves, and the series are loaded again when the resource is restarted.
+	data: Option<Resource<Result<Vec<ChartSeries>, String>>>,
+	/// The content rendered while the `data` resource is loading. Defaults to a loading message.
+	loading: Option<Element>,
+	/// The text displayed in place of the chart when the series are empty. Defaults to `No data` when the
+	/// series are provided by a `data` resource.
+	#[props(into)]
+	no_data_text: Option<String>,
+	/// The width of the chart.
+	#[props(into, default = "100%".to_string())]
+	width: String,
+	/// The height of the chart.
+	#[props(into, default = "auto".to_string())]
+	height: String,
+	/// The content rendered in place of the chart when the options or series are not valid. Defaults
+	/// to a message describing the error.
+	fallback: Option<Element>,
+	/// Called with the error when the chart could not be built or rendered.
+	on_error: Option<EventHandler<ApexChartsError>>,
+	/// Called with a handle to the chart once it has been rendered, and again whenever the chart is recreated.
+	/// The handle is used to control the chart from the parent component.
+	on_ready: Option<EventHandler<ChartHandle>>,
+) -> Element {
+	let generated_id = use_hook(generate_id);
+	let id = id.unwrap_or(generated_id);
+	let mut element = use_signal(|| None::<HtmlElement>);
+	let mut error = use_signal(|| None::<ApexChartsError>);
+	let chart = use_hook(|| Rc::new(RefCell::new(None::<ApexChart>)));
+	let previous = use_hook(|| Rc::new(RefCell::new(None::<ChartConfig>)));
+	let mounted_id = use_hook(|| Rc::new(RefCell::new(None::<String>)));
+
+	// The series to draw: `Ok(None)` while the `data` resource is loading and `Err` when it failed to load.
+	let state = match data.map(|data| data.read().clone()) {
+		None => Ok(Some(series)),
+		Some(None) => Ok(None),
+		Some(Some(Ok(series))) => Ok(Some(series)),
+		Some(Some(Err(reason))) => Err(reason),
+	};
+	let config = state.map(|series| series.map(|series| ChartConfig {
+		id: Some(id.clone()),
+		r#type,
+		series,
+		options,
+		width,
+		height,
+		no_data_text: no_data_text.or_else(|| data.map(|_| "No data".to_string())),
+	}));
+
+	{
+		let chart = chart.clone();
+		use_effect(use_reactive((&config,), move |(config,)| {
+			let Some(element) = element() else {
+				return;
+			};
+			let mut chart = chart.borrow_mut();
+			let current = chart.clone();
+			let result = match config {
+				Ok(Some(config)) => {
+					let previous = previous.replace(Some(config.clone()));
+					draw(&mut chart, &config, previous.as_ref(), &element).map(|_| true)
+				},
+				Ok(None) => Ok(false),
+				Err(reason) => Err(ApexChartsError::LoadFailed(reason)),
+			};
+			if result != Ok(true) {
+				if let Some(chart) = chart.take() {
+					chart.destroy();
+				}
+				previous.replace(None);
+			}
+			if let (Err(chart_error), Some(on_error)) = (&result, on_error) {
+				on_error.call(chart_error.clone());
+			}
+			if let (Some(on_ready), Some(new_chart), Some(config)) = (on_ready, chart.as_ref(), previous.borrow().as_ref()) {
+				if current.as_ref() != Some(new_chart) {
+					on_ready.call(ChartHandle::new(new_chart.clone(), config.r#type.clone()));
+				}
+			}
+			error.set(result.err());
+		}));
+	}
+	{
+		let mounted_id = mounted_id.clone();
+		use_effect(use_reactive((&id,), move |(id,)| {
+			if let Some(previous_id) = mounted_id.replace(Some(id.clone())) {
+				unregister_id(&previous_id);
+			}
+			register_id(&id);
+		}));
+	}
+	use_drop(move || {
+		if let Some(chart) = chart.borrow_mut().take() {
+			chart.destroy();
+		}
+		if let Some(id) = mounted_id.borrow_mut().take() {
+			unregister_id(&id);
+		}
+	});
+
+	let is_loading = data.is_some_and(|data| data.read().is_none());
+	rsx! {
+		div {
+			id: id,
+			onmounted: move |event| {
+				let mounted = event.data().downcast::<web_sys::Element>().and_then(|element| element.dyn_ref::<HtmlElement>().cloned());
+				element.set(mounted);
+			},
+			if is_loading {
+				match loading {
+					Some(loading) => loading,
+					None => rsx! { div { class: "apexcharts-loading", "Loading..." } },
+				}
+			}
+			if let Some(chart_error) = error() {
+				match fallback {
+					Some(fallback) => fallback,
+					None => rsx! {
+						div {
+							class: "apexcharts-error",
+							{chart_error.to_string()}
+							if let (ApexChartsError::LoadFailed(_), Some(mut data)) = (&chart_error, data) {
+								button { class: "apexcharts-retry", onclick: move |_| data.restart(), "Retry" }
+							}
+						}
+					},
+				}
+			}
+		}
+	}
+}
@@ -0,0 +1,90 @@
+//! This module contains the error type returned by the fallible operations of the crate.
+
+use std::fmt::Display;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::JsValue;
+
+/// Represents the errors that can occur when building or rendering a chart.
+///
+/// New variants may be added in minor releases, so matches on this enum need a wildcard arm.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum ApexChartsError {
+	/// The options provided for the chart are not valid JSON. Contains the reason the options were rejected.
+	InvalidOptions(String),
+	/// The chart data could not be serialized. Contains the reason the serialization failed.
+	Serialization(String),
+	/// A data point cannot be represented in JSON. This happens for `NaN` and infinite values.
+	NonFiniteValue(f64),
+	/// An exception was thrown by the ApexCharts library. Contains the message of the exception.
+	JsError(String),
+	/// The data of the chart could not be loaded. Contains the error returned by the data source.
+	LoadFailed(String),
+	/// The chart cannot be rendered without a browser. Contains the reason the chart is not supported.
+	Unsupported(String),
+	/// The chart could not be rasterized to an image. Contains the reason the rasterization failed.
+	Rasterization(String),
+	/// The chart has been destroyed, eg. because the component rendering it has been unmounted.
+	Destroyed,
+}
+
+impl Display for ApexChartsError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			ApexChartsError::InvalidOptions(reason) => write!(f, "Invalid chart options: {}", reason),
+			ApexChartsError::Serialization(reason) => write!(f, "Failed to serialize the chart data: {}", reason),
+			ApexChartsError::NonFiniteValue(value) => write!(f, "The value {} cannot be represented in a chart", value),
+			ApexChartsError::JsError(message) => write!(f, "ApexCharts error: {}", message),
+			ApexChartsError::LoadFailed(reason) => write!(f, "Failed to load the chart data: {}", reason),
+			ApexChartsError::Unsupported(reason) => write!(f, "Unsupported chart: {}", reason),
+			ApexChartsError::Rasterization(reason) => write!(f, "Failed to rasterize the chart: {}", reason),
+			ApexChartsError::Destroyed => write!(f, "The chart has been destroyed"),
+		}
+	}
+}
+
+impl std::error::Error for ApexChartsError {}
+
+impl From<serde_json::Error> for ApexChartsError {
+	fn from(error: serde_json::Error) -> Self {
+		ApexChartsError::Serialization(error.to_string())
+	}
+}
+
+#[cfg(feature = "wasm")]
+impl From<serde_wasm_bindgen::Error> for ApexChartsError {
+	fn from(error: serde_wasm_bindgen::Error) -> Self {
+		ApexChartsError::Serialization(error.to_string())
+	}
+}
+
+#[cfg(feature = "wasm")]
+impl From<JsValue> for ApexChartsError {
+	fn from(value: JsValue) -> Self {
+		let message = value.as_string()
+			.or_else(|| js_sys_message(&value))
+			.unwrap_or_else(|| format!("{:?}", value));
+		ApexChartsError::JsError(message)
+	}
+}
+
+/// Extracts the message from a JavaScript `Error` object.
+#[cfg(feature = "wasm")]
+fn js_sys_message(value: &JsValue) -> Option<String> {
+	use wasm_bindgen::JsCast;
+	use wasm_bindgen_futures::js_sys;
+
+	value.dyn_ref::<js_sys::Error>().map(|error| String::from(error.message()))
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::prelude::ApexChartsError;
+
+	#[test]
+	pub fn test_error_from_invalid_json() {
+		let error: ApexChartsError = serde_json::from_str::<serde_json::Value>("{").unwrap_err().into();
+		assert!(matches!(error, ApexChartsError::Serialization(_)));
+		assert_eq!(ApexChartsError::NonFiniteValue(f64::INFINITY).to_string(), "The value inf cannot be represented in a chart");
+	}
+}
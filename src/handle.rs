@@ -0,0 +1,271 @@
+//! This module contains the drawing of the charts and the handle used to control a rendered chart from Rust.
+
+use serde_json::{Map, Value};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::js_sys;
+use crate::bindings::ApexChart;
+use crate::config::ChartConfig;
+use crate::error::ApexChartsError;
+use crate::options::{ChartSeries, ChartType, SeriesData};
+#[cfg(any(feature = "yew", feature = "leptos", feature = "dioxus", feature = "sycamore"))]
+use crate::options::try_to_jsvalue;
+
+/// A handle to a chart rendered by one of the chart components.
+///
+/// The handle provides typed methods to control the chart imperatively, eg. from a custom legend or toolbar.
+/// It is handed out by the components once the chart has been rendered, through the `on_ready` callback in
+/// Yew and the `handle` signal in Leptos. Once the chart has been destroyed, eg. when the component is unmounted, the
+/// methods of the handle return [ApexChartsError::Destroyed].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChartHandle {
+	chart: ApexChart,
+	r#type: ChartType,
+}
+
+impl ChartHandle {
+	/// Creates a handle for the chart of the given type. The type determines how the series are passed to ApexCharts.
+	pub fn new(chart: ApexChart, r#type: ChartType) -> Self {
+		Self { chart, r#type }
+	}
+
+	/// Returns the underlying chart bindings.
+	pub fn chart(&self) -> &ApexChart {
+		&self.chart
+	}
+
+	/// Returns the type of the chart.
+	pub fn chart_type(&self) -> &ChartType {
+		&self.r#type
+	}
+
+	/// Returns whether the chart has been destroyed, eg. because the component rendering it has been unmounted or
+	/// has recreated the chart. The methods controlling the chart return [ApexChartsError::Destroyed] afterwards.
+	pub fn is_destroyed(&self) -> bool {
+		self.chart.is_destroyed()
+	}
+
+	/// Replaces the series of the chart. For the `Pie`, `Donut` and `RadialBar` charts the [SeriesData::Radial] data
+	/// of the first series is split into the values and the `labels` of the chart, as when the chart is rendered.
+	pub fn update_series(&self, series: &[ChartSeries]) -> Result<(), ApexChartsError> {
+		let chart = self.rendered_chart()?;
+		match SeriesUpdate::new(&self.r#type, series)? {
+			SeriesUpdate::Series(series) => chart.update_series(&js_sys::JSON::parse(&series.to_string())?, None),
+			SeriesUpdate::Options(options) => {
+				chart.update_options(&js_sys::JSON::parse(&options.to_string())?, Some(true), Some(false), Some(true));
+			},
+		}
+		Ok(())
+	}
+
+	/// Updates the options of the chart from the configuration. The new options are merged with the existing options.
+	pub fn update_options(&self, config: &ChartConfig) -> Result<(), ApexChartsError> {
+		self.rendered_chart()?.update_options(&config.to_jsvalue()?, None, None, None);
+		Ok(())
+	}
+
+	/// Appends a new series to the existing series of the chart.
+	pub fn append_series(&self, series: &ChartSeries) -> Result<(), ApexChartsError> {
+		self.rendered_chart()?.append_series(&series.try_to_jsvalue()?, None);
+		Ok(())
+	}
+
+	/// Appends data points to the existing series. The data is provided in the same order as the series of the chart.
+	pub fn append_data(&self, data: &[SeriesData]) -> Result<(), ApexChartsError> {
+		let chart = self.rendered_chart()?;
+		let array = js_sys::Array::new();
+		for series_data in data {
+			let series = js_sys::Object::new();
+			js_sys::Reflect::set(&series, &JsValue::from_str("data"), &series_data.try_to_jsvalue()?)?;
+			array.push(&series);
+		}
+		chart.append_data(&array);
+		Ok(())
+	}
+
+	/// Zooms the chart into the range between the start and end X values.
+	pub fn zoom_x(&self, start: f64, end: f64) -> Result<(), ApexChartsError> {
+		self.rendered_chart()?.zoom_x_f64(start, end);
+		Ok(())
+	}
+
+	/// Shows the series with the given name if it is hidden.
+	pub fn show_series(&self, name: &str) -> Result<(), ApexChartsError> {
+		self.rendered_chart()?.show_series(name);
+		Ok(())
+	}
+
+	/// Hides the series with the given name if it is visible.
+	pub fn hide_series(&self, name: &str) -> Result<(), ApexChartsError> {
+		self.rendered_chart()?.hide_series(name);
+		Ok(())
+	}
+
+	/// Toggles the visibility of the series with the given name.
+	pub fn toggle_series(&self, name: &str) -> Result<(), ApexChartsError> {
+		self.rendered_chart()?.toggle_series(name);
+		Ok(())
+	}
+
+	/// Shows all the hidden series and resets the zoom of the chart.
+	pub fn reset(&self) -> Result<(), ApexChartsError> {
+		self.rendered_chart()?.reset_series(Some(true), Some(true));
+		Ok(())
+	}
+
+	/// Returns the chart, or [ApexChartsError::Destroyed] when it has been destroyed.
+	fn rendered_chart(&self) -> Result<&ApexChart, ApexChartsError> {
+		match self.chart.is_destroyed() {
+			true => Err(ApexChartsError::Destroyed),
+			false => Ok(&self.chart),
+		}
+	}
+}
+
+/// The payload replacing the series of a chart.
+#[derive(Debug, PartialEq)]
+enum SeriesUpdate {
+	/// The series passed to `updateSeries`.
+	Series(Value),
+	/// The options passed to `updateOptions` for the radial charts, with the values and the labels of the chart.
+	Options(Value),
+}
+
+impl SeriesUpdate {
+	/// Builds the payload replacing the series of a chart of the type, in the same way as the options of the chart.
+	fn new(r#type: &ChartType, series: &[ChartSeries]) -> Result<Self, ApexChartsError> {
+		let (series, labels) = ChartConfig::new(r#type.clone(), series.to_vec()).series_value()?;
+		Ok(match labels {
+			Some(labels) => {
+				let mut options = Map::new();
+				options.insert("series".to_string(), series);
+				options.insert("labels".to_string(), labels);
+				SeriesUpdate::Options(Value::Object(options))
+			},
+			None => SeriesUpdate::Series(series),
+		})
+	}
+}
+
+/// Renders the chart the first time it is drawn. Afterwards, the existing chart is updated in place when only its
+/// size or series changed. The chart is recreated when its type, id, options or `noData` text change, as `updateOptions`
+/// merges the new options into the existing ones and cannot remove an option or change the id of a chart.
+#[cfg(any(feature = "yew", feature = "leptos", feature = "dioxus", feature = "sycamore"))]
+pub(crate) fn draw(
+	chart: &mut Option<ApexChart>,
+	config: &ChartConfig,
+	previous: Option<&ChartConfig>,
+	element: &web_sys::HtmlElement
+) -> Result<(), ApexChartsError> {
+	if let (Some(current), Some(previous)) = (chart.as_ref(), previous) {
+		if is_updatable(previous, config) {
+			if previous.width != config.width || previous.height != config.height {
+				current.update_options(&config.to_jsvalue()?, None, None, None);
+			} else if config.is_radial() {
+				current.update_options(&config.to_jsvalue()?, Some(true), Some(false), Some(true));
+			} else {
+				current.update_series(&try_to_jsvalue(&config.series)?, Some(false));
+			}
+			return Ok(());
+		}
+	}
+	render_options(chart, &config.to_json()?, element)
+}
+
+/// Returns whether the chart drawn from the previous configuration can be updated in place to the configuration.
+#[cfg(any(feature = "yew", feature = "leptos", feature = "dioxus", feature = "sycamore"))]
+fn is_updatable(previous: &ChartConfig, config: &ChartConfig) -> bool {
+	previous.r#type == config.r#type
+		&& previous.id == config.id
+		&& previous.options == config.options
+		&& previous.no_data_text == config.no_data_text
+}
+
+/// Renders a new chart from the options serialized to JSON, destroying the existing chart if any.
+#[cfg(any(feature = "yew", feature = "leptos", feature = "dioxus", feature = "sycamore"))]
+pub(crate) fn render_options(
+	chart: &mut Option<ApexChart>,
+	options: &str,
+	element: &web_sys::HtmlElement
+) -> Result<(), ApexChartsError> {
+	if let Some(current) = chart.take() {
+		current.destroy();
+	}
+	*chart = Some(render_chart(options, element)?);
+	Ok(())
+}
+
+/// Creates a chart from the options serialized to JSON and renders it into the element. A chart that fails to render is
+/// destroyed, so that the listeners and elements it already added are not left behind.
+#[cfg(any(feature = "yew", feature = "leptos", feature = "dioxus", feature = "sycamore", feature = "custom-element"))]
+pub(crate) fn render_chart(options: &str, element: &web_sys::HtmlElement) -> Result<ApexChart, ApexChartsError> {
+	let chart = ApexChart::try_new(&JsValue::from_str(options))?;
+	if let Err(error) = chart.try_render_into(element) {
+		// The error of the rendering is reported rather than the one of destroying a partially drawn chart.
+		let _ = chart.try_destroy();
+		return Err(error.into());
+	}
+	Ok(chart)
+}
+
+#[cfg(test)]
+mod tests {
+	use serde_json::json;
+	use crate::prelude::{ChartSeries, ChartType, SeriesData};
+	use super::SeriesUpdate;
+
+	fn series(data: SeriesData) -> Vec<ChartSeries> {
+		vec![
+			ChartSeries {
+				name: "Series 1".to_string(),
+				data,
+				color: "#008FFB".to_string(),
+				r#type: None,
+				z_index: None,
+			}
+		]
+	}
+
+	#[test]
+	pub fn test_series_update() {
+		let update = SeriesUpdate::new(&ChartType::Line, &series(SeriesData::Single(vec![10, 20]))).unwrap();
+		let SeriesUpdate::Series(series) = update else {
+			panic!("unexpected update {:?}", update);
+		};
+		assert_eq!(series[0]["data"], json!([10, 20]));
+	}
+
+	#[test]
+	pub fn test_radial_series_update() {
+		let data = SeriesData::Radial(vec![("Rent".to_string(), 60.0), ("Food".to_string(), 40.0)]);
+		for r#type in [ChartType::Pie, ChartType::Donut, ChartType::RadialBar] {
+			assert_eq!(
+				SeriesUpdate::new(&r#type, &series(data.clone())).unwrap(),
+				SeriesUpdate::Options(json!({"series": [60.0, 40.0], "labels": ["Rent", "Food"]}))
+			);
+		}
+	}
+
+	#[cfg(any(feature = "yew", feature = "leptos", feature = "dioxus", feature = "sycamore"))]
+	#[test]
+	pub fn test_is_updatable() {
+		use crate::prelude::ChartConfig;
+		use super::is_updatable;
+
+		let config = ChartConfig {
+			id: Some("sales".to_string()),
+			options: r#"{"title": {"text": "Sales"}}"#.to_string(),
+			..ChartConfig::new(ChartType::Line, series(SeriesData::Single(vec![10, 20])))
+		};
+		let updated = ChartConfig {
+			series: series(SeriesData::Single(vec![30])),
+			height: "300px".to_string(),
+			..config.clone()
+		};
+		assert!(is_updatable(&config, &updated));
+		// The options are merged by `updateOptions`, so a removed option would remain applied.
+		assert!(!is_updatable(&config, &ChartConfig { options: String::new(), ..config.clone() }));
+		assert!(!is_updatable(&config, &ChartConfig { id: Some("revenue".to_string()), ..config.clone() }));
+		assert!(!is_updatable(&config, &ChartConfig { no_data_text: Some("No sales".to_string()), ..config.clone() }));
+		assert!(!is_updatable(&config, &ChartConfig { r#type: ChartType::Bar, ..config.clone() }));
+	}
+}
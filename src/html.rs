@@ -0,0 +1,173 @@
+//! This module contains the export of charts to HTML documents embedding the vendored ApexCharts library.
+
+use serde_json::Value;
+use crate::config::ChartConfig;
+use crate::error::ApexChartsError;
+use crate::options::{ChartSeries, ChartType};
+
+/// The vendored ApexCharts library. It is a JavaScript module, which defines `ApexCharts` as a global when it is run.
+const LIBRARY: &str = include_str!("bindings/chart.js");
+
+/// Declared before the library so that its UMD wrappers always define `ApexCharts` as a global, instead of registering
+/// it as an AMD or CommonJS module when the page provides `define` or `module`, eg. RequireJS in Jupyter Notebook.
+const LIBRARY_SCOPE: &str = "const define = undefined, exports = undefined, module = undefined;";
+
+/// The id of the element the chart is rendered into when the configuration has no id.
+const DEFAULT_ELEMENT_ID: &str = "chart";
+
+/// Renders a self-contained HTML page displaying the interactive chart.
+///
+/// The page embeds the vendored ApexCharts library and the options of the chart, so it can be opened in a browser,
+/// attached to an email or archived without any other file or a network connection. It does not require the **wasm**
+/// feature. The `options` are the ApexCharts options in json, as in [ChartConfig::options].
+///
+/// # Example
+///
+/// ```rust
+/// use apexcharts_rs::prelude::{render_standalone_html, ChartSeries, ChartType, SeriesData};
+///
+/// let series = vec![
+///     ChartSeries {
+///         name: "Sales".to_string(),
+///         data: SeriesData::Single(vec![10, 20, 30]),
+///         color: "#008FFB".to_string(),
+///         r#type: None,
+///         z_index: None,
+///     }
+/// ];
+/// let html = render_standalone_html(ChartType::Bar, &series, r#"{"title": {"text": "Sales"}}"#).unwrap();
+/// assert!(html.starts_with("<!DOCTYPE html>"));
+/// ```
+pub fn render_standalone_html(chart_type: ChartType, series: &[ChartSeries], options: &str) -> Result<String, ApexChartsError> {
+	ChartConfig {
+		options: options.to_string(),
+		..ChartConfig::new(chart_type, series.to_vec())
+	}.to_standalone_html()
+}
+
+impl ChartConfig {
+	/// Renders a self-contained HTML page displaying the interactive chart. See [render_standalone_html].
+	///
+	/// The chart is rendered into an element with the id of the configuration, or `chart` when it has no id. The
+	/// title of the page is the title of the chart.
+	pub fn to_standalone_html(&self) -> Result<String, ApexChartsError> {
+		let options = self.to_value()?;
+		let title = options.pointer("/title/text").and_then(Value::as_str).unwrap_or("Chart");
+		Ok(format!(
+			"<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n<title>{}</title>\n{}\n</head>\n<body>\n{}\n</body>\n</html>\n",
+			escape(title),
+			library_script(),
+			chart_markup(&options, self.id.as_deref().unwrap_or(DEFAULT_ELEMENT_ID))
+		))
+	}
+}
+
+/// Returns the vendored ApexCharts library as a JavaScript module defining `ApexCharts` as a global.
+///
+/// This is used to ship the library once as a file loaded by all the pages of a site, instead of embedding it in each
+/// page. The file must be loaded as a module, with `<script type="module">`, before the scripts rendering the charts.
+pub fn library_module() -> String {
+	format!("{}\n{}", LIBRARY_SCOPE, LIBRARY)
+}
+
+/// Returns the script element running the ApexCharts library. It must precede the markup of the charts.
+pub(crate) fn library_script() -> String {
+	format!("<script type=\"module\">\n{}\n</script>", library_module())
+}
+
+/// Returns the script element loading the ApexCharts library from the URL, eg. of a file containing [library_module].
+/// It must precede the markup of the charts.
+#[cfg(feature = "markdown")]
+pub(crate) fn library_url_script(url: &str) -> String {
+	format!("<script type=\"module\" src=\"{}\"></script>", escape(url))
+}
+
+/// Returns the element the chart is rendered into, followed by the script rendering the chart with the options.
+///
+/// The scripts are modules so that they run after the library script, in the order of the page. The element and the
+/// script are separated by a blank line so that they remain separate HTML blocks when embedded in Markdown.
+pub(crate) fn chart_markup(options: &Value, element_id: &str) -> String {
+	format!(
+		"<div id=\"{id}\"></div>\n\n<script type=\"module\">\nnew ApexCharts(document.getElementById({element}), {options}).render();\n</script>",
+		id = escape(element_id),
+		element = script_json(&Value::String(element_id.to_string())),
+		options = script_json(options)
+	)
+}
+
+/// Returns the element the chart is rendered into, followed by a single script rendering the chart. This is used where
+/// the scripts may not run in the order of the page and where the other outputs may be gone, eg. in the outputs of a
+/// notebook, which are inserted into the page dynamically and can be cleared.
+///
+/// The script embeds the ApexCharts library and runs it only if the page has not loaded it yet. As a module cannot be run
+/// conditionally, the library is imported from a blob URL when `window.ApexCharts` is not defined.
+#[cfg(feature = "evcxr")]
+pub(crate) fn inline_chart_markup(options: &Value, element_id: &str) -> String {
+	format!(
+		"<div id=\"{id}\"></div>\n<script type=\"module\">\nif (!window.ApexCharts) {{\nconst url = URL.createObjectURL(new Blob([{library}], {{ type: \"text/javascript\" }}));\nawait import(url);\nURL.revokeObjectURL(url);\n}}\nnew ApexCharts(document.getElementById({element}), {options}).render();\n</script>",
+		id = escape(element_id),
+		library = script_json(&Value::String(library_module())),
+		element = script_json(&Value::String(element_id.to_string())),
+		options = script_json(options)
+	)
+}
+
+/// Serializes the value to JSON that can be embedded in a script element. The `<` characters, which can only appear
+/// in strings, are escaped so that a string cannot close the script element.
+fn script_json(value: &Value) -> String {
+	value.to_string().replace('<', "\\u003c")
+}
+
+/// Escapes the text for use in the HTML markup.
+pub(crate) fn escape(text: &str) -> String {
+	text.replace('&', "&amp;")
+		.replace('<', "&lt;")
+		.replace('>', "&gt;")
+		.replace('"', "&quot;")
+		.replace('\'', "&#39;")
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::prelude::{render_standalone_html, ApexChartsError, ChartConfig, ChartSeries, ChartType, SeriesData};
+
+	fn series() -> Vec<ChartSeries> {
+		vec![
+			ChartSeries {
+				name: "Sales".to_string(),
+				data: SeriesData::Single(vec![10, 20, 30]),
+				color: "#008FFB".to_string(),
+				r#type: None,
+				z_index: None,
+			}
+		]
+	}
+
+	#[test]
+	pub fn test_standalone_html() {
+		let html = render_standalone_html(ChartType::Bar, &series(), r#"{"title": {"text": "Q1 </script> & more"}}"#).unwrap();
+		assert!(html.contains("<title>Q1 &lt;/script&gt; &amp; more</title>"));
+		assert!(html.contains("ApexCharts v3.40.0"));
+		assert!(html.contains("<div id=\"chart\"></div>"));
+		assert!(html.contains("new ApexCharts(document.getElementById(\"chart\"), {"));
+		assert!(html.contains(r#""text":"Q1 \u003c/script> & more""#));
+		assert!(html.contains(r##""series":[{"color":"#008FFB","data":[10,20,30],"name":"Sales"}]"##));
+		// Only the library and the chart scripts are closed.
+		assert_eq!(html.matches("</script>").count(), 2);
+	}
+
+	#[test]
+	pub fn test_standalone_html_id() {
+		let config = ChartConfig {
+			id: Some("sales\"chart".to_string()),
+			..ChartConfig::new(ChartType::Line, series())
+		};
+		let html = config.to_standalone_html().unwrap();
+		assert!(html.contains("<title>Chart</title>"));
+		assert!(html.contains("<div id=\"sales&quot;chart\"></div>"));
+		assert!(html.contains(r#"document.getElementById("sales\"chart")"#));
+
+		let error = render_standalone_html(ChartType::Line, &series(), "[]").unwrap_err();
+		assert!(matches!(error, ApexChartsError::InvalidOptions(_)));
+	}
+}
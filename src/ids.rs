@@ -0,0 +1,51 @@
+//! This module contains the generation and tracking of the ids of the charts rendered on the page.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// The prefix of the generated chart ids.
+pub(crate) const ID_PREFIX: &str = "apexchart-";
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+	/// The number of mounted charts using each id.
+	static MOUNTED_IDS: RefCell<HashMap<String, usize>> = RefCell::new(HashMap::new());
+}
+
+/// Generates a unique id for a chart. The ids are generated in the order the charts are created.
+pub(crate) fn generate_id() -> String {
+	format!("{}{}", ID_PREFIX, NEXT_ID.fetch_add(1, Ordering::Relaxed))
+}
+
+/// Records that a chart using the id has been mounted, warning in the console if another mounted
+/// chart already uses the same id.
+pub(crate) fn register_id(id: &str) {
+	let count = MOUNTED_IDS.with(|ids| {
+		let mut ids = ids.borrow_mut();
+		let count = ids.entry(id.to_string()).or_insert(0);
+		*count += 1;
+		*count
+	});
+	if count > 1 {
+		web_sys::console::warn_1(&format!(
+			"apexcharts-rs: {} charts use the id `{}`. Chart ids must be unique, otherwise the charts are rendered in the same element.",
+			count,
+			id
+		).into());
+	}
+}
+
+/// Records that a chart using the id has been unmounted.
+pub(crate) fn unregister_id(id: &str) {
+	MOUNTED_IDS.with(|ids| {
+		let mut ids = ids.borrow_mut();
+		if let Some(count) = ids.get_mut(id) {
+			*count -= 1;
+			if *count == 0 {
+				ids.remove(id);
+			}
+		}
+	});
+}
@@ -1,8 +1,12 @@
 //! This module contains the Leptos component for rendering ApexCharts in a Leptos application.
-//!
+
+use leptos::html::Div;
 use leptos::prelude::*;
-use wasm_bindgen::JsValue;
-use crate::prelude::{ChartSeries, ChartType};
+use crate::ids::{self, register_id, unregister_id};
+#[cfg(any(feature = "ssr", feature = "hydrate"))]
+use crate::ids::ID_PREFIX;
+use crate::handle::{draw, render_options};
+use crate::prelude::{ApexChart, ApexChartsError, ChartConfig, ChartHandle, ChartSeries, ChartType};
 
 /// An ApexCharts component for Leptos. 
 ///
@@ -10,132 +14,266 @@ use crate::prelude::{ChartSeries, ChartType};
 /// such as line, bar, pie, donut, and radial bar charts. To use this component, you need to enable the `leptos` feature
 /// in the `apexcharts-rs` crate. 
 ///
+/// All the properties of the chart accept either plain values or signals such as `ReadSignal`, `RwSignal`
+/// and `Memo`. The chart is updated in place when the series or size change, and recreated when the type, options
+/// or `no_data_text` change.
+///
+/// The series can also be loaded asynchronously by passing a `LocalResource` as the `data` property. A
+/// loading indicator is displayed until the resource resolves, and a retry button, refetching the resource,
+/// is displayed if it fails to load.
+///
+/// To control the chart from the parent component, pass a `RwSignal::new_local(None)` as the `handle` property.
+/// It is set to a [ChartHandle] once the chart has been rendered.
 ///
 /// # Example
 ///
-/// ```rust,ignore
-/// use leptos::*;
-/// use apexcharts_rs::prelude::{ApexChartComponent, ChartSeries, ChartType, SeriesData};
-/// 
+/// ```rust,no_run
+/// use leptos::mount::mount_to_body;
+/// use leptos::prelude::*;
+/// use apexcharts_rs::prelude::{ChartSeries, ChartType, SeriesData};
+/// use apexcharts_rs::leptos::ApexChartComponent;
+///
 /// #[component]
 /// fn App() -> impl IntoView {
-///   let series = vec![
-///     ChartSeries {
-///       name: "Series 1".to_string(),
-///       data: SeriesData::Single(vec![10, 20, 30, 40, 50]),
-///       color: "#008FFB".to_string(),
-///       r#type: None,
-///       z_index: None,
-/// 	 }
-///    ];
-///   let (series, _) = create_signal(series);
-///   view! {
-///    <ApexChartComponent
-/// 	  id={"my_area_chart".to_string()}
-/// 	  r#type={ChartType::Area}
-/// 	  series={series}
-/// 	  width={"100%".to_string()}
-/// 	  height={"300px".to_string()}
-///   />
-///  }
+///     let series = vec![
+///         ChartSeries {
+///             name: "Series 1".to_string(),
+///             data: SeriesData::Single(vec![10, 20, 30, 40, 50]),
+///             color: "#008FFB".to_string(),
+///             r#type: None,
+///             z_index: None,
+///         }
+///     ];
+///     let (series, _) = signal(series);
+///     view! {
+///         <ApexChartComponent
+///             id="my_area_chart"
+///             r#type=ChartType::Area
+///             series=series
+///             width="100%"
+///             height="300px"
+///         />
+///     }
 /// }
-/// 
+///
 /// fn main() {
-///   mount_to_body(App);
+///     mount_to_body(App);
 /// }
-/// 
-/// 
+/// ```
 #[component]
 pub fn ApexChartComponent(
-	/// The type of the chart.
-	#[prop(default = ChartType::Line)]
-	r#type: ChartType,
-	/// The options for the chart in json.
-	#[prop(default = String::default())]
-	options: String,
-	/// The unique id of the chart.
-	id: String,
-	/// The series to be rendered in the chart.
-	series: ReadSignal<Vec<ChartSeries>>,
+	/// The type of the chart. The chart is recreated when the type changes.
+	#[prop(into, default = ChartType::Line.into())]
+	r#type: Signal<ChartType>,
+	/// The options for the chart in json. The chart is recreated when they change.
+	#[prop(into, default = String::default().into())]
+	options: Signal<String>,
+	/// The unique id of the chart. It is also set as the `chart.id` option of ApexCharts. A unique id, stable
+	/// between server rendering and hydration, is generated when it is not provided.
+	#[prop(optional, into)]
+	id: Option<String>,
+	/// The series to be rendered in the chart. It is ignored when a `data` resource is provided.
+	#[prop(into, default = Vec::new().into())]
+	series: Signal<Vec<ChartSeries>>,
+	/// Loads the series of the chart asynchronously. A loading indicator is displayed until the resource
+	/// resolves, and the series are loaded again when the resource is refetched.
+	#[prop(optional)]
+	data: Option<LocalResource<Result<Vec<ChartSeries>, String>>>,
+	/// The view rendered while the `data` resource is loading. Defaults to a loading message.
+	#[prop(optional, into)]
+	loading: Option<ViewFn>,
+	/// The text displayed in place of the chart when the series are empty. Defaults to `No data` when the
+	/// series are provided by a `data` resource.
+	#[prop(optional, into)]
+	no_data_text: MaybeProp<String>,
 	/// The width of the chart.
-	#[prop(default = "100%".to_string())]
-	width: String,
+	#[prop(into, default = "100%".into())]
+	width: Signal<String>,
 	/// The height of the chart.
-	#[prop(default = "auto".to_string())]
-	height: String
+	#[prop(into, default = "auto".into())]
+	height: Signal<String>,
+	/// The view rendered in place of the chart when the options or series are not valid. Defaults
+	/// to a message describing the error.
+	#[prop(optional, into)]
+	fallback: Option<ViewFn>,
+	/// Called with the error when the chart could not be built or rendered.
+	#[prop(optional, into)]
+	on_error: Option<Callback<ApexChartsError>>,
+	/// Set to a handle to the chart once it has been rendered, and back to `None` when the chart is destroyed.
+	/// The handle is used to control the chart from the parent component.
+	#[prop(optional)]
+	handle: Option<RwSignal<Option<ChartHandle>, LocalStorage>>,
 ) -> impl IntoView {
-	let id_clone = id.clone();
-	Effect::new(move |_| {
-		use serde_json::Value;
-		use crate::prelude::{SeriesData, ApexChart};
-
-		let mut labels_data = None;
-		let series_data = match r#type {
-			ChartType::Pie | ChartType::Donut | ChartType::RadialBar => {
-				let chart_series = series.get();
-				match chart_series.first() {
-					Some(chart_serie) => {
-						match chart_serie.data {
-							SeriesData::Radial(ref data) => {
-								let data_values = data.iter().map(|(_, y)| *y).collect::<Vec<_>>();
-								labels_data = Some(data.iter().map(|(x, _)| x.clone()).collect::<Vec<_>>());
-								serde_json::to_value(data_values).unwrap_or(Value::Array(vec![]))
-							},
-							_ => {
-								serde_json::to_value(series.get()).unwrap_or(Value::Array(vec![]))
-							}
-						}
-					},
-					_=> {
-						Value::Array(vec![])
-					}
-				}
-			},
-			_=> {
-				serde_json::to_value(series.get()).unwrap_or(Value::Array(vec![]))
+	let id = id.unwrap_or_else(generate_id);
+	let node_ref = NodeRef::<Div>::new();
+	let error = RwSignal::new(None::<ApexChartsError>);
+	let chart = StoredValue::new_local(None::<ApexChart>);
+	// Publishes the current chart to the handle signal when it has changed.
+	let publish = move || {
+		if let Some(handle) = handle {
+			let current = chart.try_with_value(|chart| chart.clone().zip(r#type.try_get_untracked()))
+				.flatten()
+				.map(|(chart, r#type)| ChartHandle::new(chart, r#type));
+			if handle.try_with_untracked(|handle| handle != &current) == Some(true) {
+				handle.try_set(current);
 			}
-		};
-		let options = if options.is_empty() {
-			format!(
-				r#"{{
-					"chart": {{
-						"type": "{}",
-						"width": "{}",
-						"height": "{}"
-					}},
-					"series": {}
-					{}
-				}}"#,
-				r#type,
-				width,
-				height,
-				series_data,
-				if let Some(labels) = labels_data {
-					format!(r#","labels": {}"#, serde_json::to_string(&labels).unwrap_or("[]".to_string()))
-				} else {
-					"".to_string()
+		}
+	};
+	let destroy = move || {
+		chart.try_update_value(|chart| {
+			if let Some(chart) = chart.take() {
+				chart.destroy();
+			}
+		});
+		publish();
+	};
+	let report = move |chart_error: Option<ApexChartsError>| {
+		if let (Some(chart_error), Some(on_error)) = (&chart_error, on_error) {
+			on_error.run(chart_error.clone());
+		}
+		error.set(chart_error);
+	};
+	let chart_id = id.clone();
+	let config = move |series: Vec<ChartSeries>| ChartConfig {
+		id: Some(chart_id.clone()),
+		r#type: r#type.get(),
+		series,
+		options: options.get(),
+		width: width.get(),
+		height: height.get(),
+		no_data_text: no_data_text.get().or_else(|| data.map(|_| "No data".to_string())),
+	};
+	// On the server, the options are serialized into the placeholder so that the chart is rendered from them
+	// after hydration. The series loaded from a `data` resource are not available on the server.
+	#[cfg(feature = "ssr")]
+	let server_options = data.is_none().then(|| config.clone()(series.get_untracked()).to_json().ok()).flatten();
+	#[cfg(not(feature = "ssr"))]
+	let server_options = None::<String>;
+	Effect::new(move |previous: Option<Option<ChartConfig>>| {
+		let element = node_ref.get()?;
+		let series = match data.map(|data| data.get()) {
+			None => series.get(),
+			Some(None) => {
+				destroy();
+				report(None);
+				return None;
+			},
+			Some(Some(result)) => match (*result).clone() {
+				Ok(series) => series,
+				Err(reason) => {
+					destroy();
+					report(Some(ApexChartsError::LoadFailed(reason)));
+					return None;
 				}
-			)
-		} else {
-			let mut options = serde_json::from_str::<Value>(&options).unwrap_or_else(|_| panic!("Invalid JSON: {}", options));
-			options["chart"]["type"] = Value::String(r#type.to_string());
-			options["chart"]["width"] = Value::String(width.clone());
-			options["chart"]["height"] = Value::String(height.clone());
-			options["series"] = series_data;
-			if let Some(labels) = labels_data {
-				options["labels"] = Value::Array(
-					labels
-						.iter()
-						.map(|label| Value::String(label.clone()))
-						.collect()
-				);
 			}
-			serde_json::to_string(&options).unwrap_or_default()
 		};
-		let chart = ApexChart::new(&JsValue::from_str(&options));
-		chart.render(&id_clone);
+		let config = config(series);
+		// The first render after hydration uses the options serialized on the server instead of serializing them again.
+		let server_options = previous.is_none().then(|| element.get_attribute(OPTIONS_ATTRIBUTE)).flatten();
+		if server_options.is_some() {
+			let _ = element.remove_attribute(OPTIONS_ATTRIBUTE);
+		}
+		let previous = previous.flatten();
+		let chart_error = chart
+			.try_update_value(|chart| {
+				let result = match &server_options {
+					Some(options) if chart.is_none() => render_options(chart, options, &element),
+					_ => draw(chart, &config, previous.as_ref(), &element),
+				};
+				if result.is_err() {
+					if let Some(chart) = chart.take() {
+						chart.destroy();
+					}
+				}
+				result
+			})
+			.and_then(Result::err);
+		publish();
+		report(chart_error);
+		Some(config)
+	});
+	let mounted_id = id.clone();
+	Effect::new(move |_| register_id(&mounted_id));
+	let unmounted_id = id.clone();
+	on_cleanup(move || {
+		destroy();
+		unregister_id(&unmounted_id);
 	});
+	let is_loading = move || data.is_some_and(|data| data.with(Option::is_none));
+	// The size of the chart is reserved before it is rendered so that the page does not jump when it appears.
 	view! {
-		<div id={id.clone()}></div>
+		<div
+			id={id}
+			node_ref=node_ref
+			style:width=move || width.get()
+			style:min-height=move || height.get()
+			data-apexcharts-options=server_options
+		>
+			{move || is_loading().then(|| match &loading {
+				Some(loading) => loading.run(),
+				None => view! {
+					<div class="apexcharts-loading">"Loading..."</div>
+				}.into_any(),
+			})}
+			{move || error.get().map(|error| match &fallback {
+				Some(fallback) => fallback.run(),
+				None => {
+					let retry = data.filter(|_| matches!(error, ApexChartsError::LoadFailed(_))).map(|data| view! {
+						<button class="apexcharts-retry" on:click=move |_| data.refetch()>"Retry"</button>
+					});
+					view! {
+						<div class="apexcharts-error">{error.to_string()}{retry}</div>
+					}.into_any()
+				},
+			})}
+		</div>
+	}
+}
+
+/// The attribute of the placeholder rendered on the server containing the serialized options of the chart.
+const OPTIONS_ATTRIBUTE: &str = "data-apexcharts-options";
+
+/// Generates the id of a chart. When rendering on the server or hydrating, the id is taken from the shared
+/// context so that the same id is generated on the server and in the browser.
+fn generate_id() -> String {
+	#[cfg(any(feature = "ssr", feature = "hydrate"))]
+	{
+		let shared_context = Owner::current_shared_context()
+			.filter(|context| !context.is_browser() || context.get_is_hydrating());
+		if let Some(context) = shared_context {
+			return format!("{}h{}", ID_PREFIX, context.next_id().into_inner());
+		}
+	}
+	ids::generate_id()
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+	use leptos::prelude::*;
+	use crate::prelude::{ChartConfig, ChartSeries, ChartType, SeriesData};
+	use crate::leptos::ApexChartComponent;
+
+	#[test]
+	pub fn test_server_placeholder() {
+		let series = vec![
+			ChartSeries {
+				name: "Series 1".to_string(),
+				data: SeriesData::Single(vec![10, 20]),
+				color: "#008FFB".to_string(),
+				r#type: None,
+				z_index: None,
+			}
+		];
+		let owner = Owner::new();
+		let html = owner.with(|| view! {
+			<ApexChartComponent id="sales".to_string() r#type=ChartType::Bar series=series.clone() height="300px".to_string() />
+		}.to_html());
+		let options = ChartConfig {
+			id: Some("sales".to_string()),
+			height: "300px".to_string(),
+			..ChartConfig::new(ChartType::Bar, series)
+		}.to_json().unwrap();
+		assert!(html.contains(r#"style="width:100%;min-height:300px;""#), "{}", html);
+		assert!(html.contains(&format!(r#"data-apexcharts-options="{}""#, options.replace('"', "&quot;"))), "{}", html);
 	}
 }
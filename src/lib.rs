@@ -9,6 +9,32 @@
 //! 
 //! - **yew**: Enables support for rendering ApexCharts in Yew applications.
 //! - **leptos**: Enables support for rendering ApexCharts in Leptos applications.
+//! - **dioxus**: Enables support for rendering ApexCharts in Dioxus web applications.
+//! - **sycamore**: Enables support for rendering ApexCharts in Sycamore applications.
+//! - **custom-element**: Provides the `<apex-chart>` custom element for rendering ApexCharts in pages that do not use a
+//!   Rust UI framework. Register it with [prelude::register_chart_element].
+//! - **static-render**: Renders line, area, bar, pie and donut charts to SVG images natively with
+//!   [prelude::ChartConfig::to_svg], eg. for emails, PDFs and server side reports. It does not require the **wasm** feature.
+//! - **html**: Exports the charts to self-contained HTML pages embedding the ApexCharts library with
+//!   [prelude::render_standalone_html], without a Wasm build.
+//! - **evcxr**: Displays [prelude::Chart] values as interactive charts in evcxr Jupyter notebooks.
+//! - **markdown**: Renders the `apexchart` code blocks of Markdown documents into charts with
+//!   [prelude::render_chart_events] for pulldown-cmark, or with the `mdbook-apexcharts` preprocessor for mdBook.
+//! - **png**: Rasterizes the static SVG images to PNG with [prelude::ChartConfig::to_png], using embedded fonts.
+//! - **ssr** and **hydrate**: Enable these alongside the corresponding Leptos or Yew features when rendering the Leptos
+//!   or Yew components on the server and hydrating them in the browser.
+//! 
+//! The crate supports Rust 1.72 and later. The **leptos**, **dioxus** and **sycamore** features require Rust 1.76, 1.79
+//! and 1.94 respectively, the minimum supported versions of the frameworks.
+//! 
+//! The Wasm bindings are provided by the default **wasm** feature. The data and options model ([prelude::ChartSeries],
+//! [prelude::SeriesData], [prelude::ChartType] and [prelude::ChartConfig]) does not depend on it, so a server can build
+//! the charts natively by disabling the default features:
+//! 
+//! ```toml
+//! [dependencies]
+//! apexcharts-rs = { version = "0.1", default-features = false }
+//! ```
 //! 
 //! ## Usage
 //! 
@@ -21,9 +47,11 @@
 //! 
 //! To render a chart in a Yew application, you can use the `ApexChartComponent` component:
 //! 
-//! ```rust,ignore
+#![cfg_attr(feature = "yew", doc = "```rust,no_run")]
+#![cfg_attr(not(feature = "yew"), doc = "```rust,ignore")]
 //! use yew::prelude::*;
-//! use apexcharts_rs::prelude::{ApexChartComponent, ApexChartComponentProps, ChartSeries, SeriesData, ChartType};
+//! use apexcharts_rs::prelude::{ChartSeries, SeriesData, ChartType};
+//! use apexcharts_rs::yew::ApexChartComponent;
 //! 
 //! #[function_component]
 //! pub fn App() -> Html {
@@ -48,15 +76,22 @@
 //! }
 //! 
 //! fn main() {
+//!   // The `csr` feature of Yew is required to render the application in the browser.
+//! # #[cfg(any())]
 //!   yew::Renderer::<App>::new().render();
 //! }
 //! ```
 //! 
-//! The equivalent component for Leptos applications is `ApexChartComponent` with the `leptos` feature enabled.
+//! The equivalent component for Leptos applications is `ApexChartComponent` with the `leptos` feature enabled. Both
+//! features can be enabled at the same time, the components are then available as `apexcharts_rs::yew::ApexChartComponent`
+//! and `apexcharts_rs::leptos::ApexChartComponent` respectively.
 //! 
-//! ```rust,ignore
-//! use leptos::*;
-//! use apexcharts_rs::prelude::{ApexChartComponent, ChartSeries, ChartType, SeriesData};
+#![cfg_attr(feature = "leptos", doc = "```rust,no_run")]
+#![cfg_attr(not(feature = "leptos"), doc = "```rust,ignore")]
+//! use leptos::mount::mount_to_body;
+//! use leptos::prelude::*;
+//! use apexcharts_rs::prelude::{ChartSeries, ChartType, SeriesData};
+//! use apexcharts_rs::leptos::ApexChartComponent;
 //! 
 //! #[component]
 //! fn App() -> impl IntoView {
@@ -69,14 +104,14 @@
 //!       z_index: None,
 //!     }
 //!   ];
-//!   let (series, _) = create_signal(series);
+//!   let (series, _) = signal(series);
 //!   view! {
 //!     <ApexChartComponent
-//!       id={"my_area_chart".to_string()}
-//!       r#type={ChartType::Area}
-//!       series={series}
-//!       width={"100%".to_string()}
-//!       height={"300px".to_string()}
+//!       id="my_area_chart"
+//!       r#type=ChartType::Area
+//!       series=series
+//!       width="100%"
+//!       height="300px"
 //!     />
 //!   }
 //! }
@@ -96,23 +131,61 @@
 #![allow(long_running_const_eval)] // This was added to suppress the warning about long-running const evaluation.
 
 mod options;
+#[cfg(feature = "wasm")]
 mod bindings;
+mod config;
+mod error;
+#[cfg(feature = "wasm")]
+mod handle;
+#[cfg(any(feature = "yew", feature = "leptos", feature = "dioxus", feature = "sycamore"))]
+mod ids;
 
 #[cfg(feature = "yew")]
-mod yew;
+pub mod yew;
+// The framework features require the minimum supported Rust versions of the frameworks.
 #[cfg(feature = "leptos")]
-mod leptos;
-
-// Ensure that only one of the "yew" or "leptos" features is enabled to avoid clashing of the `ApexChartsComponent` type exported.
-#[cfg(all(feature = "yew", feature = "leptos"))]
-compile_error!("Only one of the \"yew\" or \"leptos\" features can be enabled at a time");
+#[clippy::msrv = "1.76"]
+pub mod leptos;
+#[cfg(feature = "dioxus")]
+#[clippy::msrv = "1.79"]
+pub mod dioxus;
+#[cfg(feature = "sycamore")]
+#[clippy::msrv = "1.94"]
+pub mod sycamore;
+#[cfg(feature = "custom-element")]
+mod custom_element;
+#[cfg(feature = "static-render")]
+mod svg;
+#[cfg(feature = "png")]
+mod png;
+#[cfg(feature = "html")]
+mod html;
+#[cfg(feature = "evcxr")]
+mod notebook;
+#[cfg(feature = "markdown")]
+mod markdown;
 
 pub mod prelude {
 	//! Re-exports commonly used items.
+	//!
+	//! The chart components are not exported from the prelude. They are available from the module of their framework,
+	//! eg. `apexcharts_rs::yew::ApexChartComponent` and `apexcharts_rs::leptos::ApexChartComponent`, so that the
+	//! framework features can be enabled together.
+	#[cfg(feature = "wasm")]
 	pub use crate::bindings::ApexChart;
-	pub use crate::options::{ChartType, ChartSeries, SeriesData, to_jsvalue};
-	#[cfg(feature = "yew")]
-	pub use crate::yew::{ApexChartComponent, ApexChartComponentProps};
-	#[cfg(feature = "leptos")]
-	pub use crate::leptos::ApexChartComponent;
+	pub use crate::config::ChartConfig;
+	pub use crate::error::ApexChartsError;
+	#[cfg(feature = "wasm")]
+	pub use crate::handle::ChartHandle;
+	pub use crate::options::{ChartType, ChartSeries, SeriesData};
+	#[cfg(feature = "wasm")]
+	pub use crate::options::{to_jsvalue, try_to_jsvalue};
+	#[cfg(feature = "html")]
+	pub use crate::html::{library_module, render_standalone_html};
+	#[cfg(feature = "evcxr")]
+	pub use crate::notebook::Chart;
+	#[cfg(feature = "markdown")]
+	pub use crate::markdown::{markdown_to_html, render_chart_blocks, render_chart_blocks_with, render_chart_events, ChartBlockOptions};
+	#[cfg(feature = "custom-element")]
+	pub use crate::custom_element::{register_chart_element, CHART_ELEMENT_NAME};
 }
@@ -0,0 +1,360 @@
+//! This module contains the rendering of the `apexchart` code blocks of Markdown documents into charts.
+
+use pulldown_cmark::{CodeBlockKind, Event, Parser, Tag, TagEnd};
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+use crate::config::ChartConfig;
+use crate::error::ApexChartsError;
+use crate::html::{chart_markup, library_script, library_url_script};
+use crate::options::{ChartSeries, ChartType};
+
+/// The language of the code blocks rendered into charts.
+const CHART_LANGUAGE: &str = "apexchart";
+
+/// The definition of a chart in an `apexchart` code block, in JSON or TOML.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ChartDefinition {
+	/// The type of the chart with its ApexCharts name, eg. `line` or `radialBar`.
+	#[serde(default = "line", deserialize_with = "chart_type")]
+	r#type: ChartType,
+	#[serde(default)]
+	series: Vec<ChartSeries>,
+	/// The ApexCharts options.
+	options: Option<Value>,
+	id: Option<String>,
+	width: Option<String>,
+	height: Option<String>,
+	no_data_text: Option<String>,
+}
+
+fn line() -> ChartType {
+	ChartType::Line
+}
+
+fn chart_type<'de, D: Deserializer<'de>>(deserializer: D) -> Result<ChartType, D::Error> {
+	String::deserialize(deserializer)?.parse().map_err(serde::de::Error::custom)
+}
+
+/// The options of the rendering of the `apexchart` code blocks with [render_chart_blocks_with].
+///
+/// # Example
+///
+/// ```rust
+/// use apexcharts_rs::prelude::{render_chart_blocks_with, ChartBlockOptions};
+///
+/// let options = ChartBlockOptions {
+///     id_prefix: "apexchart-sales".to_string(),
+///     library_url: Some("../apexcharts.js".to_string()),
+/// };
+/// let markdown = "```apexchart toml\ntype = \"bar\"\n```\n";
+/// let rendered = render_chart_blocks_with(markdown, &options).unwrap();
+/// assert!(rendered.starts_with(r#"<script type="module" src="../apexcharts.js"></script>"#));
+/// assert!(rendered.contains(r#"<div id="apexchart-sales-1"></div>"#));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChartBlockOptions {
+	/// The prefix of the ids of the charts without an `id`, which are numbered in the order of the document. Documents
+	/// displayed in the same page or site need different prefixes so that the ids of their charts do not conflict.
+	pub id_prefix: String,
+	/// The URL of the ApexCharts library loaded before the first chart, eg. a file containing
+	/// [library_module](crate::prelude::library_module) shipped once for all the documents. The library is embedded
+	/// in the document when it is `None`.
+	pub library_url: Option<String>,
+}
+
+impl Default for ChartBlockOptions {
+	fn default() -> Self {
+		Self { id_prefix: "apexchart".to_string(), library_url: None }
+	}
+}
+
+/// Renders the `apexchart` code blocks among the events of a pulldown-cmark parser into charts.
+///
+/// The code blocks contain the definition of a chart in JSON or TOML, with its `type`, `series` and `options` and
+/// optionally its `id`, `width`, `height` and `no_data_text`. The series are deserialized into [ChartSeries], so an
+/// invalid definition is reported as an error when the document is rendered rather than in the browser. The format is
+/// given after the language, eg. `apexchart toml`, or otherwise detected from the contents of the block.
+///
+/// The code blocks are replaced by HTML events rendering the charts, the first of which embeds the ApexCharts
+/// library. The other events are unchanged.
+///
+/// # Example
+///
+/// ````rust
+/// use pulldown_cmark::{html, Parser};
+/// use apexcharts_rs::prelude::render_chart_events;
+///
+/// let markdown = r#"
+/// ```apexchart toml
+/// type = "bar"
+/// options = { title = { text = "Sales" } }
+///
+/// [[series]]
+/// name = "Sales"
+/// data = { Single = [10, 20, 30] }
+/// ```
+/// "#;
+/// let events = render_chart_events(Parser::new(markdown)).unwrap();
+/// let mut page = String::new();
+/// html::push_html(&mut page, events.into_iter());
+/// assert!(page.contains("new ApexCharts("));
+/// ````
+pub fn render_chart_events<'a>(events: impl IntoIterator<Item = Event<'a>>) -> Result<Vec<Event<'a>>, ApexChartsError> {
+	let mut rendered = Vec::new();
+	let options = ChartBlockOptions::default();
+	let mut charts = ChartCounter { count: 0, options: &options };
+	let mut block = None::<(String, String)>;
+	for event in events {
+		block = match (block, event) {
+			(None, Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(info)))) if is_chart_block(&info) => Some((info.to_string(), String::new())),
+			(None, event) => {
+				rendered.push(event);
+				None
+			},
+			(Some((info, source)), Event::End(TagEnd::CodeBlock)) => {
+				rendered.push(Event::Html(charts.render(&info, &source, None)?.into()));
+				None
+			},
+			(Some((info, mut source)), Event::Text(text)) => {
+				source.push_str(&text);
+				Some((info, source))
+			},
+			(block, _) => block,
+		};
+	}
+	Ok(rendered)
+}
+
+/// Renders the Markdown document to HTML, rendering its `apexchart` code blocks into charts. See [render_chart_events].
+pub fn markdown_to_html(markdown: &str) -> Result<String, ApexChartsError> {
+	let mut html = String::new();
+	pulldown_cmark::html::push_html(&mut html, render_chart_events(Parser::new(markdown))?.into_iter());
+	Ok(html)
+}
+
+/// Replaces the `apexchart` code blocks of the Markdown document with the HTML rendering the charts, leaving the rest of
+/// the document unchanged. See [render_chart_events].
+///
+/// This is used to preprocess documents that are rendered to HTML by another tool, eg. the chapters of an mdBook. The
+/// charts of the code blocks nested in blockquotes or list items remain in them, and the errors locate the invalid code
+/// blocks by their line.
+pub fn render_chart_blocks(markdown: &str) -> Result<String, ApexChartsError> {
+	render_chart_blocks_with(markdown, &ChartBlockOptions::default())
+}
+
+/// Replaces the `apexchart` code blocks of the Markdown document with the HTML rendering the charts, with the ids
+/// and the loading of the library given by the options. See [render_chart_blocks].
+pub fn render_chart_blocks_with(markdown: &str, options: &ChartBlockOptions) -> Result<String, ApexChartsError> {
+	let mut rendered = String::with_capacity(markdown.len());
+	let mut charts = ChartCounter { count: 0, options };
+	let mut end = 0;
+	let mut block = None::<(String, String, usize)>;
+	for (event, range) in Parser::new(markdown).into_offset_iter() {
+		block = match (block, event) {
+			(None, Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(info)))) if is_chart_block(&info) => {
+				Some((info.to_string(), String::new(), range.start))
+			},
+			(Some((info, source, start)), Event::End(TagEnd::CodeBlock)) => {
+				// The line of the opening fence, which may follow a list marker or a blockquote marker on its line.
+				let line = markdown[..start].matches('\n').count() + 1;
+				let line_start = markdown[..start].rfind('\n').map_or(0, |index| index + 1);
+				rendered.push_str(&markdown[end..start]);
+				rendered.push_str(&nest(&charts.render(&info, &source, Some(line))?, &markdown[line_start..start]));
+				end = range.end;
+				None
+			},
+			(Some((info, mut source, start)), Event::Text(text)) => {
+				source.push_str(&text);
+				Some((info, source, start))
+			},
+			(block, _) => block,
+		};
+	}
+	rendered.push_str(&markdown[end..]);
+	Ok(rendered)
+}
+
+/// Prefixes the lines of the HTML after the first with the markers of the blockquotes and the indentation of the list
+/// items containing a code block, given by the text preceding its opening fence on its line, so that the HTML remains in
+/// the same containers as the code block.
+fn nest(html: &str, fence_prefix: &str) -> String {
+	let prefix = fence_prefix.chars()
+		.map(|c| if c == '>' || c.is_whitespace() { c } else { ' ' })
+		.collect::<String>();
+	html.split('\n')
+		.enumerate()
+		.map(|(index, line)| match (index, line.is_empty()) {
+			(0, _) => line.to_string(),
+			(_, true) => prefix.trim_end().to_string(),
+			(_, false) => format!("{}{}", prefix, line),
+		})
+		.collect::<Vec<_>>()
+		.join("\n")
+}
+
+/// Returns whether the info string of a code block starts with the `apexchart` language.
+fn is_chart_block(info: &str) -> bool {
+	info.split(|c: char| c.is_whitespace() || c == ',').next() == Some(CHART_LANGUAGE)
+}
+
+/// Counts the charts of a document to give each of them an id and to load the ApexCharts library only once.
+struct ChartCounter<'a> {
+	count: usize,
+	options: &'a ChartBlockOptions,
+}
+
+impl ChartCounter<'_> {
+	/// Returns the HTML rendering the chart defined in a code block. The line of the code block is included in the errors.
+	fn render(&mut self, info: &str, source: &str, line: Option<usize>) -> Result<String, ApexChartsError> {
+		let invalid = |reason: String| ApexChartsError::InvalidOptions(match line {
+			Some(line) => format!("invalid `{}` block at line {}: {}", CHART_LANGUAGE, line, reason),
+			None => format!("invalid `{}` block: {}", CHART_LANGUAGE, reason),
+		});
+		let format = info.split(|c: char| c.is_whitespace() || c == ',').filter(|token| !token.is_empty()).nth(1);
+		let definition = match format {
+			Some("json") => serde_json::from_str::<ChartDefinition>(source).map_err(|error| error.to_string()),
+			Some("toml") => toml::from_str::<ChartDefinition>(source).map_err(|error| error.to_string()),
+			Some(format) => Err(format!("unknown format `{}`, expected json or toml", format)),
+			None if source.trim_start().starts_with('{') => serde_json::from_str::<ChartDefinition>(source).map_err(|error| error.to_string()),
+			None => toml::from_str::<ChartDefinition>(source).map_err(|error| error.to_string()),
+		}.map_err(invalid)?;
+
+		self.count += 1;
+		let defaults = ChartConfig::new(definition.r#type, definition.series);
+		let config = ChartConfig {
+			id: definition.id,
+			options: definition.options.map(|options| options.to_string()).unwrap_or_default(),
+			width: definition.width.unwrap_or(defaults.width.clone()),
+			height: definition.height.unwrap_or(defaults.height.clone()),
+			no_data_text: definition.no_data_text,
+			..defaults
+		};
+		let options = config.to_value().map_err(|error| invalid(error.to_string()))?;
+		let element_id = config.id.clone().unwrap_or_else(|| format!("{}-{}", self.options.id_prefix, self.count));
+		let chart = chart_markup(&options, &element_id);
+		// The library is loaded before the first chart, separated by a blank line to remain a separate HTML block.
+		Ok(match (self.count, &self.options.library_url) {
+			(1, Some(url)) => format!("{}\n\n{}", library_url_script(url), chart),
+			(1, None) => format!("{}\n\n{}", library_script(), chart),
+			_ => chart,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use pulldown_cmark::{Event, Parser};
+	use crate::prelude::{markdown_to_html, render_chart_blocks, render_chart_blocks_with, render_chart_events, ApexChartsError, ChartBlockOptions};
+
+	const JSON_BLOCK: &str = r#"```apexchart
+{"type": "pie", "series": [{"name": "Expenses", "data": {"Radial": [["Rent", 60.0], ["Food", 40.0]]}}]}
+```"#;
+
+	const TOML_BLOCK: &str = r#"```apexchart toml
+type = "radialBar"
+id = "progress"
+height = "300px"
+options = { title = { text = "Progress" } }
+
+[[series]]
+name = "Progress"
+data = { Radial = [["Done", 70.0]] }
+```"#;
+
+	#[test]
+	pub fn test_render_chart_events() {
+		let markdown = format!("# Report\n\n{}\n\nSome text.\n\n{}\n\n```rust\nlet x = 1;\n```\n", JSON_BLOCK, TOML_BLOCK);
+		let events = render_chart_events(Parser::new(&markdown)).unwrap();
+		let charts = events.iter()
+			.filter_map(|event| match event {
+				Event::Html(html) => Some(html.to_string()),
+				_ => None,
+			})
+			.collect::<Vec<_>>();
+		assert_eq!(charts.len(), 2);
+		// Only the first chart embeds the library.
+		assert!(charts[0].contains("ApexCharts v3.40.0") && !charts[1].contains("ApexCharts v3.40.0"));
+		assert!(charts[0].contains(r#"<div id="apexchart-1"></div>"#));
+		assert!(charts[0].contains(r#""labels":["Rent","Food"]"#));
+		assert!(charts[1].contains(r#"<div id="progress"></div>"#));
+		assert!(charts[1].contains(r#""type":"radialBar""#) && charts[1].contains(r#""height":"300px""#));
+
+		let html = markdown_to_html(&markdown).unwrap();
+		assert!(html.contains("<h1>Report</h1>") && html.contains("<p>Some text.</p>"));
+		assert!(html.contains(r#"<code class="language-rust">"#));
+		assert_eq!(html.matches("new ApexCharts(document.getElementById(").count(), 2);
+	}
+
+	#[test]
+	pub fn test_render_chart_blocks() {
+		let markdown = format!("# Report\n\n{}\n\nSome text.\n", TOML_BLOCK);
+		let rendered = render_chart_blocks(&markdown).unwrap();
+		assert!(rendered.starts_with("# Report\n\n<script type=\"module\">\n"));
+		assert!(rendered.ends_with("</script>\n\nSome text.\n"));
+		assert!(!rendered.contains("```"));
+
+		// The rendered document remains valid Markdown, the charts being kept as HTML blocks.
+		let html = markdown_to_html(&rendered).unwrap();
+		assert!(html.contains("<h1>Report</h1>") && html.contains("<p>Some text.</p>"));
+		assert!(html.contains(r#"<div id="progress"></div>"#));
+		assert!(!html.contains("&quot;"));
+
+		let unchanged = "# Report\n\n```json\n{}\n```\n";
+		assert_eq!(render_chart_blocks(unchanged).unwrap(), unchanged);
+	}
+
+	#[test]
+	pub fn test_render_chart_blocks_with() {
+		let markdown = format!("{}\n\n{}\n", JSON_BLOCK, JSON_BLOCK);
+		let options = ChartBlockOptions {
+			id_prefix: "apexchart-sales-md".to_string(),
+			library_url: Some("../apexcharts.js".to_string()),
+		};
+		let rendered = render_chart_blocks_with(&markdown, &options).unwrap();
+		assert!(rendered.starts_with("<script type=\"module\" src=\"../apexcharts.js\"></script>\n\n<div id=\"apexchart-sales-md-1\"></div>"));
+		assert!(rendered.contains("<div id=\"apexchart-sales-md-2\"></div>"));
+		assert!(!rendered.contains("ApexCharts v3.40.0"));
+		assert_eq!(rendered.matches("<script").count(), 3);
+	}
+
+	#[test]
+	pub fn test_render_nested_chart_blocks() {
+		// The chart in a blockquote embeds the library, whose lines must all remain in the blockquote.
+		let rendered = render_chart_blocks("> Sales:\n>\n> ```apexchart toml\n> type = \"bar\"\n> ```\n\nAfter.\n").unwrap();
+		assert!(rendered.lines().take_while(|line| !line.is_empty()).all(|line| line.starts_with('>')));
+		let html = markdown_to_html(&rendered).unwrap();
+		assert!(html.starts_with("<blockquote>\n<p>Sales:</p>\n<script type=\"module\">\n"));
+		assert!(html.contains("ApexCharts v3.40.0"));
+		assert!(html.ends_with("<div id=\"apexchart-1\"></div>\n<script type=\"module\">\nnew ApexCharts(document.getElementById(\"apexchart-1\"), {\"chart\":{\"height\":\"auto\",\"type\":\"bar\",\"width\":\"100%\"},\"series\":[]}).render();\n</script>\n</blockquote>\n<p>After.</p>\n"));
+
+		let options = ChartBlockOptions { library_url: Some("apexcharts.js".to_string()), ..ChartBlockOptions::default() };
+		let rendered = render_chart_blocks_with("- Sales:\n\n  ```apexchart toml\n  type = \"bar\"\n  ```\n- ```apexchart toml\n  type = \"pie\"\n  ```\n- Next\n", &options).unwrap();
+		assert_eq!(
+			markdown_to_html(&rendered).unwrap().lines().filter(|line| !line.starts_with("new ApexCharts(")).collect::<Vec<_>>(),
+			[
+				"<ul>", "<li>", "<p>Sales:</p>",
+				"<script type=\"module\" src=\"apexcharts.js\"></script>", "<div id=\"apexchart-1\"></div>", "<script type=\"module\">", "</script>",
+				"</li>", "<li><div id=\"apexchart-2\"></div>", "<script type=\"module\">", "</script>",
+				"</li>", "<li>", "<p>Next</p>", "</li>", "</ul>",
+			]
+		);
+	}
+
+	#[test]
+	pub fn test_invalid_chart_blocks() {
+		let error = |markdown: &str| match render_chart_blocks(markdown) {
+			Err(ApexChartsError::InvalidOptions(reason)) => reason,
+			result => panic!("unexpected result {:?}", result),
+		};
+		assert!(error("text\n\n```apexchart\n{\"type\": \"lines\"}\n```\n").starts_with("invalid `apexchart` block at line 3:"));
+		assert!(error("```apexchart\ntype = \"bar\"\nseries = [{ name = \"A\", data = [1, 2] }]\n```\n").contains("line 1"));
+		// The blocks nested in lists and blockquotes are located by the line of their fence.
+		assert!(error("text\n\n- item\n- ```apexchart\n  {\"type\": \"lines\"}\n  ```\n").contains("line 4"));
+		assert!(error("text\n> quote\n> ```apexchart\n> {\"type\": \"lines\"}\n> ```\n").contains("line 3"));
+		assert!(error("```apexchart\n{\"options\": [1]}\n```\n").contains("expected a JSON object"));
+		assert!(error("```apexchart yaml\ntype: bar\n```\n").contains("unknown format `yaml`"));
+		assert!(error("```apexchart json\n{\"colour\": \"red\"}\n```\n").contains("unknown field `colour`"));
+		assert!(render_chart_events(Parser::new("```apexchart\n{\"series\": 1}\n```\n")).is_err());
+	}
+}
@@ -0,0 +1,136 @@
+//! This module contains the display of charts in evcxr Jupyter notebooks.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+use crate::config::ChartConfig;
+use crate::error::ApexChartsError;
+use crate::html::inline_chart_markup;
+use crate::options::{ChartSeries, ChartType};
+
+static CHART_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// A chart displayed as the result of a cell in an [evcxr](https://github.com/evcxr/evcxr) Jupyter notebook.
+///
+/// Evaluating a `Chart` in a cell displays the interactive chart, rendered by the vendored ApexCharts library, so the
+/// notebook does not need a Wasm build or a network connection. The library is embedded in the output of each chart, and
+/// run only if the page has not loaded it yet, so the charts are displayed after the page of the notebook is reloaded or
+/// the output of another chart is cleared.
+///
+/// # Example
+///
+/// ```rust
+/// use apexcharts_rs::prelude::{Chart, ChartSeries, ChartType, SeriesData};
+///
+/// let chart = Chart {
+///     options: r#"{"title": {"text": "Sales"}}"#.to_string(),
+///     ..Chart::new(ChartType::Bar, vec![
+///         ChartSeries {
+///             name: "Sales".to_string(),
+///             data: SeriesData::Single(vec![10, 20, 30]),
+///             color: "#008FFB".to_string(),
+///             r#type: None,
+///             z_index: None,
+///         }
+///     ])
+/// };
+/// // In a notebook, evaluating `chart` as the last expression of a cell displays it.
+/// assert!(chart.to_html().is_ok());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Chart {
+	/// The type of the chart.
+	pub r#type: ChartType,
+	/// The series to be rendered in the chart.
+	pub series: Vec<ChartSeries>,
+	/// The options for the chart in json. An empty string is treated as no options.
+	pub options: String,
+}
+
+impl Chart {
+	/// Creates a chart with no options.
+	pub fn new(r#type: ChartType, series: Vec<ChartSeries>) -> Self {
+		Self { r#type, series, options: String::new() }
+	}
+
+	/// Returns the HTML displaying the chart: the element the chart is rendered into and the script rendering it,
+	/// which embeds the ApexCharts library. Each call uses a new id for the element, so that the outputs of the
+	/// notebook do not conflict.
+	pub fn to_html(&self) -> Result<String, ApexChartsError> {
+		let options = ChartConfig::from(self.clone()).to_value()?;
+		let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |duration| duration.subsec_nanos());
+		let id = format!("apexcharts-notebook-{}-{}", nanos, CHART_COUNT.fetch_add(1, Ordering::Relaxed));
+		Ok(inline_chart_markup(&options, &id))
+	}
+
+	/// Displays the chart in an evcxr notebook. This is called by evcxr when the chart is the result of a cell. The
+	/// error is displayed instead when the chart cannot be rendered.
+	pub fn evcxr_display(&self) {
+		print!("{}", self.evcxr_content());
+	}
+
+	/// Returns the output of the chart in the format of the evcxr display protocol.
+	fn evcxr_content(&self) -> String {
+		match self.to_html() {
+			Ok(html) => evcxr_output("text/html", &html),
+			Err(error) => evcxr_output("text/plain", &error.to_string()),
+		}
+	}
+}
+
+/// Formats the content in the evcxr display protocol.
+fn evcxr_output(mime_type: &str, content: &str) -> String {
+	format!("EVCXR_BEGIN_CONTENT {}\n{}\nEVCXR_END_CONTENT\n", mime_type, content)
+}
+
+impl From<Chart> for ChartConfig {
+	fn from(chart: Chart) -> Self {
+		ChartConfig {
+			options: chart.options,
+			..ChartConfig::new(chart.r#type, chart.series)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::prelude::{Chart, ChartSeries, ChartType, SeriesData};
+
+	fn chart() -> Chart {
+		Chart::new(ChartType::Line, vec![
+			ChartSeries {
+				name: "Sales".to_string(),
+				data: SeriesData::Single(vec![10, 20, 30]),
+				color: "#008FFB".to_string(),
+				r#type: None,
+				z_index: None,
+			}
+		])
+	}
+
+	#[test]
+	pub fn test_evcxr_content() {
+		let content = chart().evcxr_content();
+		assert!(content.starts_with("EVCXR_BEGIN_CONTENT text/html\n<div id=\"apexcharts-notebook-"));
+		assert!(content.ends_with("</script>\nEVCXR_END_CONTENT\n"));
+		// The library and the chart are in the same script, so that they run in order.
+		assert_eq!(content.matches("<script").count(), 1);
+		assert!(content.contains("const define = undefined, exports = undefined, module = undefined;"));
+		assert!(content.contains("ApexCharts v3.40.0"));
+		assert!(content.contains(r##""series":[{"color":"#008FFB","data":[10,20,30],"name":"Sales"}]"##));
+		// The library is embedded in every output, and only run when the page has not loaded it.
+		assert!(content.contains("if (!window.ApexCharts) {"));
+		assert_eq!(chart().evcxr_content().matches("ApexCharts v3.40.0").count(), 1);
+
+		let invalid = Chart { options: "[]".to_string(), ..chart() };
+		assert_eq!(
+			invalid.evcxr_content(),
+			"EVCXR_BEGIN_CONTENT text/plain\nInvalid chart options: expected a JSON object\nEVCXR_END_CONTENT\n"
+		);
+	}
+
+	#[test]
+	pub fn test_unique_ids() {
+		let id = |html: String| html.split('"').nth(1).unwrap().to_string();
+		assert_ne!(id(chart().to_html().unwrap()), id(chart().to_html().unwrap()));
+	}
+}
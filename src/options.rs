@@ -1,10 +1,14 @@
 use indexmap::IndexMap;
 use std::fmt::Display;
+use std::str::FromStr;
 use serde::{Deserialize, Serialize};
-use serde::ser::{SerializeSeq};
+use serde::ser::{Error, SerializeSeq};
 use serde_json::{Value};
+#[cfg(feature = "wasm")]
 use wasm_bindgen::JsValue;
+#[cfg(feature = "wasm")]
 use wasm_bindgen_futures::js_sys;
+use crate::error::ApexChartsError;
 
 /// Represents the type of the chart that will be rendered.
 #[derive(Clone, Debug, PartialEq, Deserialize)]
@@ -66,6 +70,32 @@ impl Display for ChartType {
 	}
 }
 
+impl FromStr for ChartType {
+	type Err = ApexChartsError;
+
+	/// Parses the chart type from its name in the ApexCharts options, eg. `area` or `radialBar`.
+	fn from_str(name: &str) -> Result<Self, Self::Err> {
+		match name {
+			"area" => Ok(ChartType::Area),
+			"bar" => Ok(ChartType::Bar),
+			"line" => Ok(ChartType::Line),
+			"boxPlot" => Ok(ChartType::BoxPlot),
+			"candlestick" => Ok(ChartType::CandleStick),
+			"rangeBar" => Ok(ChartType::RangeBar),
+			"rangeArea" => Ok(ChartType::RangeArea),
+			"heatmap" => Ok(ChartType::HeatMap),
+			"treemap" => Ok(ChartType::Treemap),
+			"funnel" => Ok(ChartType::Funnel),
+			"multiAxis" => Ok(ChartType::MultiAxis),
+			"pie" => Ok(ChartType::Pie),
+			"donut" => Ok(ChartType::Donut),
+			"radar" => Ok(ChartType::Radar),
+			"radialBar" => Ok(ChartType::RadialBar),
+			_ => Err(ApexChartsError::InvalidOptions(format!("unknown chart type `{}`", name))),
+		}
+	}
+}
+
 impl Serialize for ChartType {
 	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
 		where
@@ -134,13 +164,13 @@ impl Serialize for SeriesData {
 			SeriesData::Radial(data) => {
 				let mut seq = serializer.serialize_seq(Some(data.len()))?;
 				let data: Vec<IndexMap<String, Value>> = data.iter().map(|(x, y)| {
-					IndexMap::from_iter(
+					Ok(IndexMap::from_iter(
 						vec![
 							("x".to_string(), Value::String(x.to_string())),
-							("y".to_string(), Value::Number(serde_json::Number::from_f64(*y).unwrap()))
+							("y".to_string(), finite_number(*y).map_err(S::Error::custom)?)
 						]
-					)
-				}).collect::<Vec<_>>();
+					))
+				}).collect::<Result<Vec<_>, S::Error>>()?;
 				for item in data {
 					seq.serialize_element(&item)?;
 				}
@@ -149,13 +179,13 @@ impl Serialize for SeriesData {
 			SeriesData::CandleStick(data) => {
 				let mut seq = serializer.serialize_seq(Some(data.len()))?;
 				let data: Vec<IndexMap<String, Value>> = data.iter().map(|(x, y)| {
-					IndexMap::from_iter(
+					Ok(IndexMap::from_iter(
 						vec![
 							("x".to_string(), Value::String(x.to_string())),
-							("y".to_string(), Value::Array(y.iter().map(|v| Value::Number(serde_json::Number::from_f64(*v).unwrap())).collect::<Vec<_>>()))
+							("y".to_string(), Value::Array(y.iter().map(|v| finite_number(*v)).collect::<Result<Vec<_>, _>>().map_err(S::Error::custom)?))
 						]
-					)
-				}).collect::<Vec<_>>();
+					))
+				}).collect::<Result<Vec<_>, S::Error>>()?;
 				for item in data {
 					seq.serialize_element(&item)?;
 				}
@@ -178,7 +208,9 @@ pub struct ChartSeries {
 	pub name: String,
 	/// The data that will be rendered in the chart. Different types of charts require different types of data.
 	pub data: SeriesData,
-	/// The color of the series. This is used to set the color of the series in the chart.
+	/// The color of the series. This is used to set the color of the series in the chart. When it is empty or omitted
+	/// from the deserialized series, ApexCharts uses the colors of the theme.
+	#[serde(default)]
 	pub color: String,
 	/// The type of the series. This is used to set the type of the series in the chart. Note that this 
 	/// overrides the type of the chart provided in the `ApexChartComponent` component. Usually, you don't need to set this.
@@ -190,22 +222,49 @@ pub struct ChartSeries {
 	pub z_index: Option<i32>,
 }
 
-impl From<ChartSeries> for JsValue {
-	fn from(chart_series: ChartSeries) -> JsValue {
+#[cfg(feature = "wasm")]
+impl SeriesData {
+	/// Converts the data points into a JsValue that can be passed to the ApexCharts library. The data points
+	/// are converted into plain JavaScript arrays and objects.
+	pub fn try_to_jsvalue(&self) -> Result<JsValue, ApexChartsError> {
+		Ok(self.serialize(&serde_wasm_bindgen::Serializer::json_compatible())?)
+	}
+}
+
+#[cfg(feature = "wasm")]
+impl ChartSeries {
+	/// Converts the series into a JsValue that can be passed to the ApexCharts library.
+	///
+	/// Unlike the `From<ChartSeries>` conversion, this returns an error instead of dropping the data when the series
+	/// cannot be converted, eg. when the data contains `NaN`.
+	pub fn try_to_jsvalue(&self) -> Result<JsValue, ApexChartsError> {
 		let series = js_sys::Object::new();
-		js_sys::Reflect::set(&series, &JsValue::from_str("name"), &JsValue::from_str(&chart_series.name)).unwrap();
-		js_sys::Reflect::set(&series, &JsValue::from_str("data"), &serde_wasm_bindgen::to_value(&chart_series.data).unwrap()).unwrap();
-		js_sys::Reflect::set(&series, &JsValue::from_str("color"), &JsValue::from_str(&chart_series.color)).unwrap();
+		js_sys::Reflect::set(&series, &JsValue::from_str("name"), &JsValue::from_str(&self.name))?;
+		js_sys::Reflect::set(&series, &JsValue::from_str("data"), &self.data.try_to_jsvalue()?)?;
+		js_sys::Reflect::set(&series, &JsValue::from_str("color"), &JsValue::from_str(&self.color))?;
 
-		if let Some(series_type) = chart_series.r#type {
-			js_sys::Reflect::set(&series, &JsValue::from_str("type"), &JsValue::from_str(&series_type.to_string())).unwrap();
+		if let Some(series_type) = &self.r#type {
+			js_sys::Reflect::set(&series, &JsValue::from_str("type"), &JsValue::from_str(&series_type.to_string()))?;
 		}
-		js_sys::Reflect::set(&series, &JsValue::from_str("zIndex"), &JsValue::from_f64(chart_series.z_index.unwrap_or(0) as f64)).unwrap();
-		series.into()
+		js_sys::Reflect::set(&series, &JsValue::from_str("zIndex"), &JsValue::from_f64(self.z_index.unwrap_or(0) as f64))?;
+		Ok(series.into())
+	}
+}
+
+#[cfg(feature = "wasm")]
+impl From<ChartSeries> for JsValue {
+	/// Converts the series into a JsValue. If the series cannot be converted, the error is logged to the console and
+	/// `undefined` is returned. Use [ChartSeries::try_to_jsvalue] to handle the failure instead.
+	fn from(chart_series: ChartSeries) -> JsValue {
+		chart_series.try_to_jsvalue().unwrap_or_else(|error| {
+			web_sys::console::error_1(&format!("apexcharts-rs: {}", error).into());
+			JsValue::UNDEFINED
+		})
 	}
 }
 
 /// A helper function to convert a vector of items into a JsValue.
+#[cfg(feature = "wasm")]
 pub fn to_jsvalue<T: Into<JsValue>>(vec: Vec<T>) -> JsValue {
 	let array = js_sys::Array::new();
 	for item in vec {
@@ -214,9 +273,26 @@ pub fn to_jsvalue<T: Into<JsValue>>(vec: Vec<T>) -> JsValue {
 	array.into()
 }
 
+/// A helper function to convert a slice of series into a JsValue, failing if any of the series cannot be converted.
+#[cfg(feature = "wasm")]
+pub fn try_to_jsvalue(series: &[ChartSeries]) -> Result<JsValue, ApexChartsError> {
+	let array = js_sys::Array::new();
+	for item in series {
+		array.push(&item.try_to_jsvalue()?);
+	}
+	Ok(array.into())
+}
+
+/// Converts a floating point value into a JSON number, rejecting `NaN` and infinite values which JSON cannot represent.
+fn finite_number(value: f64) -> Result<Value, ApexChartsError> {
+	serde_json::Number::from_f64(value)
+		.map(Value::Number)
+		.ok_or(ApexChartsError::NonFiniteValue(value))
+}
+
 #[cfg(test)]
 mod tests {
-	use crate::prelude::SeriesData;
+	use crate::prelude::{ChartType, SeriesData};
 
 	#[test]
 	pub fn test_series_data_serialization() {
@@ -236,4 +312,21 @@ mod tests {
 		assert_eq!(dated_data, r#"[{"x":"2021-04-29","y":30},{"x":"2021-04-30","y":40}]"#);
 	}
 
+	#[test]
+	pub fn test_non_finite_series_data_serialization() {
+		let radial_data = serde_json::to_string(&SeriesData::Radial(vec![("Apple".to_string(), f64::NAN)]));
+		assert!(radial_data.is_err());
+
+		let candlestick_data = serde_json::to_string(&SeriesData::CandleStick(vec![("Sun".to_string(), vec![10.0, f64::INFINITY, 5.0, 15.0])]));
+		assert!(candlestick_data.is_err());
+	}
+
+	#[test]
+	pub fn test_chart_type_from_str() {
+		for chart_type in [ChartType::Area, ChartType::BoxPlot, ChartType::HeatMap, ChartType::RadialBar] {
+			assert_eq!(chart_type.to_string().parse::<ChartType>().unwrap(), chart_type);
+		}
+		assert_eq!("radialBar".parse::<ChartType>().unwrap(), ChartType::RadialBar);
+		assert!("Area".parse::<ChartType>().is_err());
+	}
 }
@@ -0,0 +1,123 @@
+//! This module contains the rasterization of the static SVG images of the charts to PNG.
+
+use std::sync::{Arc, OnceLock};
+use apexcharts_rs_fonts::{DEJAVU_SANS, DEJAVU_SANS_BOLD, FONT_FAMILY};
+use resvg::{tiny_skia, usvg};
+use crate::config::ChartConfig;
+use crate::error::ApexChartsError;
+
+/// The fonts embedded for rendering the text of the charts, independently of the fonts installed on the system. They are
+/// provided by the `apexcharts-rs-fonts` crate so that they are only downloaded with the `png` feature.
+const FONTS: [&[u8]; 2] = [DEJAVU_SANS, DEJAVU_SANS_BOLD];
+
+static FONT_DATABASE: OnceLock<Arc<usvg::fontdb::Database>> = OnceLock::new();
+
+impl ChartConfig {
+	/// Renders the chart to a PNG image, from the SVG image returned by [ChartConfig::to_svg].
+	///
+	/// Like `ApexChart::data_uri`, the image is scaled by `scale`, eg. `2.0` for a 192 DPI image, or resized to
+	/// `width` pixels keeping the aspect ratio of the chart, `width` taking precedence over `scale`. The text is drawn
+	/// with the embedded DejaVu Sans fonts.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use apexcharts_rs::prelude::{ChartConfig, ChartSeries, ChartType, SeriesData};
+	///
+	/// let config = ChartConfig::new(ChartType::Line, vec![
+	///     ChartSeries {
+	///         name: "Sales".to_string(),
+	///         data: SeriesData::Single(vec![10, 20, 30]),
+	///         color: "#008FFB".to_string(),
+	///         r#type: None,
+	///         z_index: None,
+	///     }
+	/// ]);
+	/// let png = config.to_png(Some(2.0), None).unwrap();
+	/// assert!(png.starts_with(b"\x89PNG"));
+	/// ```
+	pub fn to_png(&self, scale: Option<f32>, width: Option<u32>) -> Result<Vec<u8>, ApexChartsError> {
+		let svg = self.to_svg()?;
+		let options = usvg::Options {
+			font_family: FONT_FAMILY.to_string(),
+			fontdb: font_database(),
+			..usvg::Options::default()
+		};
+		let tree = usvg::Tree::from_str(&svg, &options).map_err(|error| ApexChartsError::Rasterization(error.to_string()))?;
+		let size = tree.size();
+		let scale = match width {
+			Some(width) => width as f32 / size.width(),
+			None => scale.unwrap_or(1.0),
+		};
+		if !scale.is_finite() || scale <= 0.0 {
+			return Err(ApexChartsError::Rasterization(format!("invalid scale {}", scale)));
+		}
+		let mut pixmap = tiny_skia::Pixmap::new((size.width() * scale).round() as u32, (size.height() * scale).round() as u32)
+			.ok_or_else(|| ApexChartsError::Rasterization("the image is empty or too large".to_string()))?;
+		resvg::render(&tree, tiny_skia::Transform::from_scale(scale, scale), &mut pixmap.as_mut());
+		pixmap.encode_png().map_err(|error| ApexChartsError::Rasterization(error.to_string()))
+	}
+}
+
+/// Returns the database of the embedded fonts, loaded the first time it is needed. The generic font families used in the
+/// SVG images are all resolved to the embedded fonts.
+fn font_database() -> Arc<usvg::fontdb::Database> {
+	FONT_DATABASE.get_or_init(|| {
+		let mut database = usvg::fontdb::Database::new();
+		for font in FONTS {
+			database.load_font_data(font.to_vec());
+		}
+		database.set_sans_serif_family(FONT_FAMILY);
+		database.set_serif_family(FONT_FAMILY);
+		database.set_monospace_family(FONT_FAMILY);
+		Arc::new(database)
+	}).clone()
+}
+
+#[cfg(test)]
+mod tests {
+	use resvg::tiny_skia::Pixmap;
+	use crate::prelude::{ApexChartsError, ChartConfig, ChartSeries, ChartType, SeriesData};
+
+	fn config() -> ChartConfig {
+		ChartConfig {
+			options: r##"{"title": {"text": "Sales"}, "chart": {"background": "#fff"}}"##.to_string(),
+			width: "400px".to_string(),
+			height: "200px".to_string(),
+			..ChartConfig::new(ChartType::Bar, vec![
+				ChartSeries {
+					name: "Sales".to_string(),
+					data: SeriesData::Single(vec![10, 20, 30]),
+					color: "#FF0000".to_string(),
+					r#type: None,
+					z_index: None,
+				}
+			])
+		}
+	}
+
+	#[test]
+	pub fn test_png_size() {
+		let png = Pixmap::decode_png(&config().to_png(None, None).unwrap()).unwrap();
+		assert_eq!((png.width(), png.height()), (400, 200));
+		let png = Pixmap::decode_png(&config().to_png(Some(2.0), None).unwrap()).unwrap();
+		assert_eq!((png.width(), png.height()), (800, 400));
+		let png = Pixmap::decode_png(&config().to_png(Some(2.0), Some(200)).unwrap()).unwrap();
+		assert_eq!((png.width(), png.height()), (200, 100));
+		assert!(matches!(config().to_png(Some(0.0), None), Err(ApexChartsError::Rasterization(_))));
+	}
+
+	#[test]
+	pub fn test_png_content() {
+		let png = Pixmap::decode_png(&config().to_png(None, None).unwrap()).unwrap();
+		// The background is opaque and the bars are drawn in the color of the series.
+		assert!(png.pixels().iter().all(|pixel| pixel.alpha() == 255));
+		assert!(png.pixels().iter().any(|pixel| (pixel.red(), pixel.green(), pixel.blue()) == (255, 0, 0)));
+		// The title is drawn with the embedded fonts.
+		let title = (10..30).flat_map(|y| (10..60).map(move |x| (x, y)))
+			.filter_map(|(x, y)| png.pixel(x, y))
+			.filter(|pixel| pixel.red() < 128)
+			.count();
+		assert!(title > 0);
+	}
+}
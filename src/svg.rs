@@ -0,0 +1,640 @@
+//! This module contains the rendering of charts to static SVG images, without a browser.
+
+use std::f64::consts::PI;
+use std::fmt::Write;
+use serde_json::Value;
+use crate::config::ChartConfig;
+use crate::error::ApexChartsError;
+use crate::options::{ChartSeries, ChartType, SeriesData};
+
+/// The width of the image when the width of the chart is not set in pixels.
+const DEFAULT_WIDTH: f64 = 600.0;
+/// The ratio of the width to the height of the image when the height of the chart is not set in pixels.
+const ASPECT_RATIO: f64 = 1.618;
+/// The default colors of the series in ApexCharts.
+const COLORS: [&str; 5] = ["#008FFB", "#00E396", "#FEB019", "#FF4560", "#775DD0"];
+const FONT_FAMILY: &str = "Helvetica, Arial, sans-serif";
+const TITLE_FONT: Font = Font { size: 14.0, color: "#263238", weight: "bold" };
+const LABEL_FONT: Font = Font { size: 11.0, color: "#373d3f", weight: "normal" };
+const LEGEND_FONT: Font = Font { size: 12.0, color: "#373d3f", weight: "normal" };
+const DATA_LABEL_FONT: Font = Font { size: 12.0, color: "#fff", weight: "bold" };
+const GRID_COLOR: &str = "#e0e0e0";
+/// The approximate number of ticks on the numeric axes.
+const TICK_COUNT: f64 = 6.0;
+/// The share of a category occupied by the bars of the category.
+const COLUMN_WIDTH: f64 = 0.7;
+/// The share of the radius of a donut chart occupied by its hole.
+const DONUT_SIZE: f64 = 0.65;
+const MONTHS: [&str; 12] = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+
+impl ChartConfig {
+	/// Renders the chart to an SVG image, approximating the default look of ApexCharts.
+	///
+	/// The `Line`, `Area`, `Bar`, `Pie` and `Donut` charts are supported. The image has the width and height of the
+	/// configuration when they are set in pixels, otherwise it is 600 pixels wide and its height follows the width.
+	/// The title, the x axis categories, the colors, the visibility of the legend and the `chart.background` color are read
+	/// from the options. The background is transparent unless its color is set.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use apexcharts_rs::prelude::{ChartConfig, ChartSeries, ChartType, SeriesData};
+	///
+	/// let config = ChartConfig {
+	///     options: r#"{"title": {"text": "Sales"}, "xaxis": {"categories": ["Jan", "Feb", "Mar"]}}"#.to_string(),
+	///     ..ChartConfig::new(ChartType::Bar, vec![
+	///         ChartSeries {
+	///             name: "Sales".to_string(),
+	///             data: SeriesData::Single(vec![10, 20, 30]),
+	///             color: "#008FFB".to_string(),
+	///             r#type: None,
+	///             z_index: None,
+	///         }
+	///     ])
+	/// };
+	/// let svg = config.to_svg().unwrap();
+	/// assert!(svg.starts_with("<svg"));
+	/// ```
+	pub fn to_svg(&self) -> Result<String, ApexChartsError> {
+		let options = self.to_value()?;
+		let width = pixels(&options["chart"]["width"]).unwrap_or(DEFAULT_WIDTH);
+		let height = pixels(&options["chart"]["height"]).unwrap_or(width / ASPECT_RATIO);
+		let mut svg = Svg::new(width, height);
+		if let Some(background) = options.pointer("/chart/background").and_then(Value::as_str).filter(|color| !color.is_empty()) {
+			svg.rect(0.0, 0.0, width, height, background);
+		}
+		let top = match options.pointer("/title/text").and_then(Value::as_str) {
+			Some(title) => {
+				svg.text(10.0, 24.0, title, "start", &TITLE_FONT);
+				45.0
+			},
+			None => 15.0,
+		};
+		match self.r#type {
+			ChartType::Line | ChartType::Area | ChartType::Bar => render_cartesian(&mut svg, self, &options, top)?,
+			ChartType::Pie | ChartType::Donut => render_pie(&mut svg, self, &options, top)?,
+			_ => return Err(ApexChartsError::Unsupported(format!("`{}` charts cannot be rendered to SVG", self.r#type))),
+		}
+		Ok(svg.finish())
+	}
+}
+
+/// The font of a text in the image.
+struct Font {
+	size: f64,
+	color: &'static str,
+	weight: &'static str,
+}
+
+/// An SVG image being drawn.
+struct Svg {
+	width: f64,
+	height: f64,
+	body: String,
+}
+
+impl Svg {
+	fn new(width: f64, height: f64) -> Self {
+		Self { width, height, body: String::new() }
+	}
+
+	fn text(&mut self, x: f64, y: f64, text: &str, anchor: &str, font: &Font) {
+		let _ = write!(
+			self.body,
+			r#"<text x="{}" y="{}" font-size="{}" text-anchor="{}" fill="{}" font-weight="{}">{}</text>"#,
+			number(x), number(y), number(font.size), anchor, font.color, font.weight, escape(text)
+		);
+	}
+
+	/// Draws a label of the x axis centered on the position, moving it inwards when it would overflow the image.
+	fn x_label(&mut self, x: f64, y: f64, text: &str) {
+		let half = text_width(text, LABEL_FONT.size) / 2.0;
+		let x = x.min(self.width - half - 2.0).max(half + 2.0);
+		self.text(x, y, text, "middle", &LABEL_FONT);
+	}
+
+	fn line(&mut self, x1: f64, y1: f64, x2: f64, y2: f64, color: &str) {
+		let _ = write!(
+			self.body,
+			r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{}" stroke-width="1"/>"#,
+			number(x1), number(y1), number(x2), number(y2), escape(color)
+		);
+	}
+
+	fn rect(&mut self, x: f64, y: f64, width: f64, height: f64, color: &str) {
+		let _ = write!(
+			self.body,
+			r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}"/>"#,
+			number(x), number(y), number(width), number(height), escape(color)
+		);
+	}
+
+	fn circle(&mut self, cx: f64, cy: f64, r: f64, color: &str) {
+		let _ = write!(self.body, r#"<circle cx="{}" cy="{}" r="{}" fill="{}"/>"#, number(cx), number(cy), number(r), escape(color));
+	}
+
+	/// Draws a path with the given data and presentation attributes.
+	fn path(&mut self, data: &str, attributes: &str) {
+		let _ = write!(self.body, r#"<path d="{}" {}/>"#, data, attributes);
+	}
+
+	/// Draws a legend centered at the bottom of the image, with a marker in the color of each item.
+	fn legend(&mut self, items: &[(String, String)]) {
+		let widths = items.iter().map(|(name, _)| 12.0 + 5.0 + text_width(name, LEGEND_FONT.size)).collect::<Vec<_>>();
+		let total = widths.iter().sum::<f64>() + 15.0 * (items.len().saturating_sub(1)) as f64;
+		let mut x = (self.width - total) / 2.0;
+		let y = self.height - 15.0;
+		for ((name, color), width) in items.iter().zip(widths) {
+			self.circle(x + 6.0, y - 4.0, 6.0, color);
+			self.text(x + 17.0, y, name, "start", &LEGEND_FONT);
+			x += width + 15.0;
+		}
+	}
+
+	fn finish(self) -> String {
+		format!(
+			r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}" font-family="{}">{}</svg>"#,
+			FONT_FAMILY,
+			self.body,
+			w = number(self.width),
+			h = number(self.height)
+		)
+	}
+}
+
+/// A series prepared for a chart with axes.
+struct PlotSeries {
+	name: String,
+	color: String,
+	/// The points of the series. The x values are the indices of the categories unless the x axis is numeric.
+	points: Vec<(f64, f64)>,
+}
+
+/// The series and the x axis of a chart with axes.
+struct Plot {
+	series: Vec<PlotSeries>,
+	/// The categories of the x axis, empty when the x axis is numeric.
+	categories: Vec<String>,
+	/// Whether the x values are timestamps, formatted as dates on the x axis.
+	dated: bool,
+}
+
+impl Plot {
+	/// Prepares the series of the chart for a chart with axes. The x axis is numeric for line and area charts whose
+	/// series all contain numeric or timestamped pairs, and made of categories otherwise.
+	fn new(config: &ChartConfig, options: &Value) -> Result<Self, ApexChartsError> {
+		let numeric = config.r#type != ChartType::Bar
+			&& !config.series.is_empty()
+			&& config.series.iter().all(|series| matches!(series.data, SeriesData::NumericPaired(_) | SeriesData::Timestamped(_)));
+		let dated = numeric && config.series.iter().any(|series| matches!(series.data, SeriesData::Timestamped(_)));
+		let mut categories = options.pointer("/xaxis/categories")
+			.and_then(Value::as_array)
+			.map(|categories| categories.iter().map(label).collect::<Vec<_>>())
+			.unwrap_or_default();
+		let mut series = Vec::new();
+		for (index, chart_series) in config.series.iter().enumerate() {
+			let points = match &chart_series.data {
+				SeriesData::Single(values) => values.iter().enumerate().map(|(x, y)| (x as f64, *y as f64)).collect(),
+				SeriesData::NumericPaired(values) | SeriesData::Timestamped(values) if numeric => {
+					let mut points = values.iter().map(|(x, y)| (*x as f64, *y as f64)).collect::<Vec<_>>();
+					points.sort_by(|a, b| a.0.total_cmp(&b.0));
+					points
+				},
+				SeriesData::NumericPaired(values) => values.iter()
+					.map(|(x, y)| (category(&mut categories, x.to_string()), *y as f64))
+					.collect(),
+				SeriesData::Timestamped(values) => values.iter()
+					.map(|(x, y)| (category(&mut categories, date(*x as f64)), *y as f64))
+					.collect(),
+				SeriesData::CategoryPaired(values) | SeriesData::Dated(values) => values.iter()
+					.map(|(x, y)| (category(&mut categories, x.clone()), *y as f64))
+					.collect(),
+				SeriesData::Radial(values) => values.iter()
+					.map(|(x, y)| (category(&mut categories, x.clone()), *y))
+					.collect(),
+				SeriesData::CandleStick(_) => {
+					return Err(ApexChartsError::Unsupported("candlestick data cannot be rendered to SVG".to_string()));
+				},
+			};
+			series.push(PlotSeries {
+				name: chart_series.name.clone(),
+				color: series_color(chart_series, options, index),
+				points,
+			});
+		}
+		if !numeric {
+			let count = series.iter()
+				.flat_map(|series| series.points.iter().map(|(x, _)| *x as usize + 1))
+				.max()
+				.unwrap_or(0);
+			while categories.len() < count {
+				categories.push((categories.len() + 1).to_string());
+			}
+		}
+		Ok(Self { series, categories, dated })
+	}
+
+	/// Returns the range of the values of all the series, including zero.
+	fn y_range(&self) -> (f64, f64) {
+		self.series.iter()
+			.flat_map(|series| series.points.iter().map(|(_, y)| *y))
+			.fold((0.0, 0.0), |(min, max), y| (f64::min(min, y), f64::max(max, y)))
+	}
+
+	/// Returns the range of the x values of all the series when the x axis is numeric.
+	fn x_range(&self) -> (f64, f64) {
+		self.series.iter()
+			.flat_map(|series| series.points.iter().map(|(x, _)| *x))
+			.fold(None, |range: Option<(f64, f64)>, x| match range {
+				Some((min, max)) => Some((min.min(x), max.max(x))),
+				None => Some((x, x)),
+			})
+			.unwrap_or((0.0, 1.0))
+	}
+}
+
+/// Draws a line, area or bar chart.
+fn render_cartesian(svg: &mut Svg, config: &ChartConfig, options: &Value, top: f64) -> Result<(), ApexChartsError> {
+	let plot = Plot::new(config, options)?;
+	let show_legend = options.pointer("/legend/show").and_then(Value::as_bool).unwrap_or(plot.series.len() > 1);
+	let bottom = svg.height - 30.0 - if show_legend { 30.0 } else { 0.0 };
+
+	let (y_min, y_max) = plot.y_range();
+	let y_ticks = ticks(y_min, y_max);
+	let y_step = y_ticks.get(1).map_or(1.0, |tick| tick - y_ticks[0]);
+	let y_labels = y_ticks.iter().map(|tick| format_tick(*tick, y_step)).collect::<Vec<_>>();
+	let left = 20.0 + y_labels.iter().map(|label| text_width(label, LABEL_FONT.size)).fold(0.0, f64::max);
+	let right = svg.width - 20.0;
+	let (y_first, y_last) = (y_ticks[0], y_ticks[y_ticks.len() - 1]);
+	let y = |value: f64| bottom - (value - y_first) / (y_last - y_first) * (bottom - top);
+
+	for (tick, label) in y_ticks.iter().zip(&y_labels) {
+		svg.line(left, y(*tick), right, y(*tick), GRID_COLOR);
+		svg.text(left - 8.0, y(*tick) + 4.0, label, "end", &LABEL_FONT);
+	}
+	svg.line(left, bottom, right, bottom, GRID_COLOR);
+
+	// The x position of a value: the center of its category for bar charts, the category itself for line and area
+	// charts, or the value on a linear scale when the axis is numeric.
+	let count = plot.categories.len().max(1) as f64;
+	let band = (right - left) / count;
+	let x_range = plot.x_range();
+	let x = |value: f64| {
+		if !plot.categories.is_empty() && config.r#type == ChartType::Bar {
+			left + (value + 0.5) * band
+		} else if !plot.categories.is_empty() {
+			if count > 1.0 { left + value * (right - left) / (count - 1.0) } else { (left + right) / 2.0 }
+		} else if x_range.1 > x_range.0 {
+			left + (value - x_range.0) / (x_range.1 - x_range.0) * (right - left)
+		} else {
+			(left + right) / 2.0
+		}
+	};
+
+	if plot.categories.is_empty() {
+		let (x_min, x_max) = x_range;
+		let x_ticks = ticks(x_min, x_max);
+		let x_step = x_ticks.get(1).map_or(1.0, |tick| tick - x_ticks[0]);
+		for tick in x_ticks.into_iter().filter(|tick| *tick >= x_min && *tick <= x_max) {
+			let label = if plot.dated { date(tick) } else { format_tick(tick, x_step) };
+			svg.x_label(x(tick), bottom + 18.0, &label);
+		}
+	} else {
+		// Only every few labels are drawn when the categories are too narrow for their labels.
+		let widest = plot.categories.iter().map(|label| text_width(label, LABEL_FONT.size)).fold(0.0, f64::max);
+		let spacing = if config.r#type == ChartType::Bar || count <= 1.0 { band } else { (right - left) / (count - 1.0) };
+		let every = ((widest + 6.0) / spacing).ceil().max(1.0) as usize;
+		for (index, label) in plot.categories.iter().enumerate().step_by(every) {
+			svg.x_label(x(index as f64), bottom + 18.0, label);
+		}
+	}
+
+	match config.r#type {
+		ChartType::Bar => {
+			let bar_width = band * COLUMN_WIDTH / plot.series.len().max(1) as f64;
+			for (index, series) in plot.series.iter().enumerate() {
+				for (value_x, value_y) in &series.points {
+					let bar_x = x(*value_x) - band * COLUMN_WIDTH / 2.0 + index as f64 * bar_width;
+					let (start, end) = (y(0.0), y(*value_y));
+					svg.rect(bar_x, start.min(end), bar_width, (start - end).abs(), &series.color);
+				}
+			}
+		},
+		_ => {
+			for series in &plot.series {
+				if series.points.is_empty() {
+					continue;
+				}
+				let line = series.points.iter().enumerate()
+					.map(|(index, (value_x, value_y))| {
+						format!("{}{} {}", if index == 0 { "M" } else { "L" }, number(x(*value_x)), number(y(*value_y)))
+					})
+					.collect::<Vec<_>>()
+					.join(" ");
+				if config.r#type == ChartType::Area {
+					let first = series.points[0].0;
+					let last = series.points[series.points.len() - 1].0;
+					let area = format!("{} L{} {} L{} {} Z", line, number(x(last)), number(y(0.0)), number(x(first)), number(y(0.0)));
+					svg.path(&area, &format!(r#"fill="{}" fill-opacity="0.35" stroke="none""#, escape(&series.color)));
+				}
+				svg.path(&line, &format!(r#"fill="none" stroke="{}" stroke-width="2""#, escape(&series.color)));
+			}
+		},
+	}
+
+	if show_legend {
+		let items = plot.series.iter().map(|series| (series.name.clone(), series.color.clone())).collect::<Vec<_>>();
+		svg.legend(&items);
+	}
+	Ok(())
+}
+
+/// Draws a pie or donut chart from the values and labels of the options.
+fn render_pie(svg: &mut Svg, config: &ChartConfig, options: &Value, top: f64) -> Result<(), ApexChartsError> {
+	let values = options["series"].as_array()
+		.and_then(|values| values.iter().map(Value::as_f64).collect::<Option<Vec<_>>>())
+		.ok_or_else(|| ApexChartsError::Unsupported(format!("`{}` charts require radial data to be rendered to SVG", config.r#type)))?;
+	let labels = (0..values.len())
+		.map(|index| options.pointer(&format!("/labels/{}", index)).map(label).unwrap_or_else(|| format!("series-{}", index + 1)))
+		.collect::<Vec<_>>();
+	let colors = (0..values.len()).map(|index| palette_color(options, index)).collect::<Vec<_>>();
+	let show_legend = options.pointer("/legend/show").and_then(Value::as_bool).unwrap_or(true);
+	let bottom = svg.height - if show_legend { 40.0 } else { 10.0 };
+
+	let cx = svg.width / 2.0;
+	let cy = (top + bottom) / 2.0;
+	let radius = ((svg.width - 20.0).min(bottom - top) / 2.0).max(0.0);
+	let inner = if config.r#type == ChartType::Donut { radius * DONUT_SIZE } else { 0.0 };
+	let total = values.iter().filter(|value| **value > 0.0).sum::<f64>();
+	let point = |r: f64, angle: f64| (cx + r * angle.cos(), cy + r * angle.sin());
+
+	// The slices start at the top and go clockwise.
+	let mut start = -PI / 2.0;
+	for (value, color) in values.iter().zip(&colors) {
+		if *value <= 0.0 || total <= 0.0 {
+			continue;
+		}
+		let sweep = value / total * 2.0 * PI;
+		let end = start + sweep;
+		let attributes = format!(r##"fill="{}" stroke="#fff" stroke-width="2" fill-rule="evenodd""##, escape(color));
+		if sweep >= 2.0 * PI - 1e-9 {
+			let mut data = circle_path(cx, cy, radius);
+			if inner > 0.0 {
+				data.push(' ');
+				data.push_str(&circle_path(cx, cy, inner));
+			}
+			svg.path(&data, &attributes);
+		} else {
+			let large = if sweep > PI { 1 } else { 0 };
+			let (outer_start, outer_end) = (point(radius, start), point(radius, end));
+			let mut data = format!(
+				"M{} {} A{r} {r} 0 {} 1 {} {}",
+				number(outer_start.0), number(outer_start.1), large, number(outer_end.0), number(outer_end.1), r = number(radius)
+			);
+			if inner > 0.0 {
+				let (inner_end, inner_start) = (point(inner, end), point(inner, start));
+				let _ = write!(
+					data,
+					" L{} {} A{r} {r} 0 {} 0 {} {} Z",
+					number(inner_end.0), number(inner_end.1), large, number(inner_start.0), number(inner_start.1), r = number(inner)
+				);
+			} else {
+				let _ = write!(data, " L{} {} Z", number(cx), number(cy));
+			}
+			svg.path(&data, &attributes);
+		}
+		let (label_x, label_y) = point(if inner > 0.0 { (radius + inner) / 2.0 } else { radius * 0.65 }, start + sweep / 2.0);
+		svg.text(label_x, label_y + 4.0, &format!("{:.1}%", value / total * 100.0), "middle", &DATA_LABEL_FONT);
+		start = end;
+	}
+
+	if show_legend {
+		let items = labels.into_iter().zip(colors).collect::<Vec<_>>();
+		svg.legend(&items);
+	}
+	Ok(())
+}
+
+/// Returns the path of a full circle, drawn as two arcs.
+fn circle_path(cx: f64, cy: f64, r: f64) -> String {
+	format!(
+		"M{} {} A{r} {r} 0 1 1 {} {} A{r} {r} 0 1 1 {} {} Z",
+		number(cx - r), number(cy), number(cx + r), number(cy), number(cx - r), number(cy), r = number(r)
+	)
+}
+
+/// Returns the index of the category, adding it to the categories if it is not one of them.
+fn category(categories: &mut Vec<String>, name: String) -> f64 {
+	match categories.iter().position(|category| category == &name) {
+		Some(index) => index as f64,
+		None => {
+			categories.push(name);
+			(categories.len() - 1) as f64
+		},
+	}
+}
+
+/// Returns the color of a series: its own color, the color set in the options or the default color.
+fn series_color(series: &ChartSeries, options: &Value, index: usize) -> String {
+	if series.color.is_empty() {
+		palette_color(options, index)
+	} else {
+		series.color.clone()
+	}
+}
+
+/// Returns the color at the index of the `colors` option, or of the default colors of ApexCharts.
+fn palette_color(options: &Value, index: usize) -> String {
+	match options["colors"].as_array().filter(|colors| !colors.is_empty()) {
+		Some(colors) => colors[index % colors.len()].as_str().unwrap_or(COLORS[index % COLORS.len()]).to_string(),
+		None => COLORS[index % COLORS.len()].to_string(),
+	}
+}
+
+/// Returns the size in pixels from a size such as `300px` or `300`, or `None` for relative sizes such as `100%`.
+fn pixels(size: &Value) -> Option<f64> {
+	match size {
+		Value::Number(size) => size.as_f64(),
+		Value::String(size) => size.trim().trim_end_matches("px").trim().parse::<f64>().ok(),
+		_ => None,
+	}.filter(|size| size.is_finite() && *size > 0.0)
+}
+
+/// Returns the text of a label from the options.
+fn label(value: &Value) -> String {
+	match value {
+		Value::String(value) => value.clone(),
+		value => value.to_string(),
+	}
+}
+
+/// Returns evenly spaced ticks with a round step covering the range.
+fn ticks(min: f64, max: f64) -> Vec<f64> {
+	let (min, max) = if max > min { (min, max) } else { (min - 1.0, max + 1.0) };
+	let raw = (max - min) / (TICK_COUNT - 1.0);
+	let magnitude = 10f64.powf(raw.log10().floor());
+	let step = match raw / magnitude {
+		fraction if fraction <= 1.0 => 1.0,
+		fraction if fraction <= 2.0 => 2.0,
+		fraction if fraction <= 5.0 => 5.0,
+		_ => 10.0,
+	} * magnitude;
+	let start = (min / step).floor() * step;
+	let count = ((max - start) / step).ceil() as usize;
+	(0..=count).map(|index| start + index as f64 * step).collect()
+}
+
+/// Formats a tick with as many decimals as its step requires.
+fn format_tick(value: f64, step: f64) -> String {
+	let decimals = if step.fract() == 0.0 { 0 } else { (-step.log10()).ceil().max(0.0) as usize };
+	let formatted = format!("{:.*}", decimals, value);
+	match formatted.trim_start_matches('-').chars().all(|c| c == '0' || c == '.') {
+		true => formatted.trim_start_matches('-').to_string(),
+		false => formatted,
+	}
+}
+
+/// Formats a timestamp, in seconds or milliseconds since the epoch, as a date such as `29 Apr`.
+fn date(timestamp: f64) -> String {
+	let seconds = if timestamp.abs() >= 1e11 { timestamp / 1000.0 } else { timestamp };
+	// Converts the days since the epoch into a civil date.
+	let days = (seconds / 86400.0).floor() as i64 + 719468;
+	let day_of_era = days.rem_euclid(146097);
+	let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+	let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+	let month = (5 * day_of_year + 2) / 153;
+	let day = day_of_year - (153 * month + 2) / 5 + 1;
+	let month = if month < 10 { month + 2 } else { month - 10 };
+	format!("{} {}", day, MONTHS[month as usize])
+}
+
+/// Formats a coordinate with at most two decimals.
+fn number(value: f64) -> String {
+	let formatted = format!("{:.2}", value);
+	let formatted = formatted.trim_end_matches('0').trim_end_matches('.');
+	match formatted {
+		"-0" => "0".to_string(),
+		formatted => formatted.to_string(),
+	}
+}
+
+/// Estimates the width of a text in the default font.
+fn text_width(text: &str, size: f64) -> f64 {
+	text.chars().count() as f64 * size * 0.6
+}
+
+/// Escapes the text for use in the SVG markup.
+fn escape(text: &str) -> String {
+	text.replace('&', "&amp;")
+		.replace('<', "&lt;")
+		.replace('>', "&gt;")
+		.replace('"', "&quot;")
+		.replace('\'', "&#39;")
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::prelude::{ApexChartsError, ChartConfig, ChartSeries, ChartType, SeriesData};
+	use super::{date, format_tick, ticks};
+
+	fn series(name: &str, data: SeriesData) -> ChartSeries {
+		ChartSeries {
+			name: name.to_string(),
+			data,
+			color: String::new(),
+			r#type: None,
+			z_index: None,
+		}
+	}
+
+	#[test]
+	pub fn test_line_and_area_svg() {
+		let config = ChartConfig {
+			options: r#"{"title": {"text": "Sales & Costs"}, "xaxis": {"categories": ["Jan", "Feb", "Mar"]}}"#.to_string(),
+			width: "500px".to_string(),
+			height: "300px".to_string(),
+			..ChartConfig::new(ChartType::Line, vec![
+				series("Sales", SeriesData::Single(vec![10, 20, 30])),
+				series("Costs", SeriesData::Single(vec![5, 15, 10])),
+			])
+		};
+		let svg = config.to_svg().unwrap();
+		assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="500" height="300""#));
+		assert!(svg.contains(">Sales &amp; Costs</text>"));
+		assert!(svg.contains(">Feb</text>"));
+		assert!(svg.contains(r##"stroke="#008FFB""##) && svg.contains(r##"stroke="#00E396""##));
+		assert_eq!(svg.matches("<path").count(), 2);
+		// The legend is displayed for multiple series.
+		assert!(svg.contains(">Costs</text>"));
+
+		let config = ChartConfig { r#type: ChartType::Area, ..config };
+		assert_eq!(config.to_svg().unwrap().matches(r#"fill-opacity="0.35""#).count(), 2);
+	}
+
+	#[test]
+	pub fn test_bar_svg() {
+		let config = ChartConfig {
+			options: r##"{"chart": {"background": "#fff"}}"##.to_string(),
+			..ChartConfig::new(ChartType::Bar, vec![
+				ChartSeries {
+					color: "#FF0000".to_string(),
+					..series("Fruits", SeriesData::CategoryPaired(vec![("Apple".to_string(), 30), ("Banana".to_string(), -10)]))
+				}
+			])
+		};
+		let svg = config.to_svg().unwrap();
+		assert!(svg.contains(r#"width="600" height="370.83""#));
+		// The background and the two bars.
+		assert!(svg.contains(r##"<rect x="0" y="0" width="600" height="370.83" fill="#fff"/>"##));
+		assert_eq!(svg.matches("<rect").count(), 3);
+		assert!(svg.contains(r##"fill="#FF0000""##));
+		assert!(svg.contains(">Banana</text>"));
+		// The legend is hidden for a single series.
+		assert!(!svg.contains(">Fruits</text>"));
+	}
+
+	#[test]
+	pub fn test_pie_and_donut_svg() {
+		let data = SeriesData::Radial(vec![("Rent".to_string(), 75.0), ("Food".to_string(), 25.0)]);
+		let config = ChartConfig {
+			options: r##"{"colors": ["#111111", "#222222"]}"##.to_string(),
+			..ChartConfig::new(ChartType::Pie, vec![series("Expenses", data)])
+		};
+		let svg = config.to_svg().unwrap();
+		assert_eq!(svg.matches("<path").count(), 2);
+		assert!(svg.contains(">75.0%</text>") && svg.contains(">25.0%</text>"));
+		assert!(svg.contains(r##"fill="#222222""##));
+		assert!(svg.contains(">Rent</text>"));
+
+		let donut = ChartConfig { r#type: ChartType::Donut, ..config }.to_svg().unwrap();
+		// The donut slices have an inner arc in addition to the outer arc.
+		assert_eq!(donut.matches(" A").count(), svg.matches(" A").count() + 2);
+
+		let single = ChartConfig::new(ChartType::Donut, vec![series("Total", SeriesData::Radial(vec![("All".to_string(), 1.0)]))]);
+		assert!(single.to_svg().unwrap().contains(">100.0%</text>"));
+	}
+
+	#[test]
+	pub fn test_unsupported_svg() {
+		let config = ChartConfig::new(ChartType::HeatMap, vec![]);
+		assert!(matches!(config.to_svg(), Err(ApexChartsError::Unsupported(_))));
+
+		let config = ChartConfig::new(ChartType::Pie, vec![series("Sales", SeriesData::Single(vec![1, 2]))]);
+		assert!(matches!(config.to_svg(), Err(ApexChartsError::Unsupported(_))));
+
+		let config = ChartConfig::new(ChartType::Bar, vec![series("Sales", SeriesData::Radial(vec![("A".to_string(), f64::NAN)]))]);
+		assert!(matches!(config.to_svg(), Err(ApexChartsError::Serialization(_))));
+	}
+
+	#[test]
+	pub fn test_ticks_and_labels() {
+		assert_eq!(ticks(0.0, 30.0), vec![0.0, 10.0, 20.0, 30.0]);
+		assert_eq!(ticks(-10.0, 30.0), vec![-10.0, 0.0, 10.0, 20.0, 30.0]);
+		assert_eq!(ticks(0.0, 0.0), vec![-1.0, -0.5, 0.0, 0.5, 1.0]);
+		assert_eq!(format_tick(0.5, 0.5), "0.5");
+		assert_eq!(format_tick(-0.0, 1.0), "0");
+		assert_eq!(date(1619683200.0), "29 Apr");
+		assert_eq!(date(1619683200000.0), "29 Apr");
+		assert_eq!(date(951782400.0), "29 Feb");
+	}
+}
@@ -0,0 +1,161 @@
+//! This module contains the Sycamore component for rendering ApexCharts in a Sycamore application.
+
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::rc::Rc;
+use sycamore::prelude::*;
+use sycamore::reactive::impl_into_maybe_dyn;
+use wasm_bindgen::JsCast;
+use web_sys::HtmlElement;
+use crate::handle::draw;
+use crate::ids::{generate_id, register_id, unregister_id};
+use crate::prelude::{ApexChart, ApexChartsError, ChartConfig, ChartHandle, ChartSeries, ChartType};
+
+impl_into_maybe_dyn!(ChartType);
+
+/// The properties of the Sycamore [ApexChartComponent].
+///
+/// The type, options, series, size and `noData` text accept either plain values or signals. The chart is updated
+/// in place when any of the signals change.
+#[derive(Props)]
+pub struct ApexChartComponentProps {
+	/// The type of the chart. The chart is recreated when the type changes.
+	#[prop(default = ChartType::Line.into(), setter(into))]
+	pub r#type: MaybeDyn<ChartType>,
+	/// The options for the chart in json. The chart is recreated when they change.
+	#[prop(default = "".into(), setter(into))]
+	pub options: MaybeDyn<Cow<'static, str>>,
+	/// The unique id of the chart. It is also set as the `chart.id` option of ApexCharts. A unique id is
+	/// generated when it is not provided.
+	#[prop(default, setter(into))]
+	pub id: Option<String>,
+	/// The series to be rendered in the chart.
+	#[prop(default = Vec::new().into(), setter(into))]
+	pub series: MaybeDyn<Vec<ChartSeries>>,
+	/// The text displayed in place of the chart when the series are empty.
+	#[prop(default = MaybeDyn::Static(None), setter(into))]
+	pub no_data_text: MaybeDyn<Option<Cow<'static, str>>>,
+	/// The width of the chart.
+	#[prop(default = "100%".into(), setter(into))]
+	pub width: MaybeDyn<Cow<'static, str>>,
+	/// The height of the chart.
+	#[prop(default = "auto".into(), setter(into))]
+	pub height: MaybeDyn<Cow<'static, str>>,
+	/// The view rendered in place of the chart when the options or series are not valid. Defaults
+	/// to a message describing the error.
+	#[prop(default)]
+	pub fallback: Option<Box<dyn Fn() -> View>>,
+	/// Called with the error when the chart could not be built or rendered.
+	#[prop(default)]
+	pub on_error: Option<Box<dyn Fn(ApexChartsError)>>,
+	/// Called with a handle to the chart once it has been rendered, and again whenever the chart is recreated.
+	/// The handle is used to control the chart from the parent component.
+	#[prop(default)]
+	pub on_ready: Option<Box<dyn Fn(ChartHandle)>>,
+}
+
+/// An ApexCharts component for Sycamore.
+///
+/// This component is used to render an ApexCharts chart in a Sycamore application. It is used to render different types
+/// of charts such as line, bar, pie, donut, and radial bar charts. To use this component, you need to enable the `sycamore`
+/// feature in the `apexcharts-rs` crate.
+///
+/// The chart is rendered once the component is mounted. Changes to the series are applied with `updateSeries` and
+/// changes to the size with `updateOptions`, while a change of type, id, options or `noData` text recreates the chart.
+/// The chart is destroyed when the component is unmounted.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use sycamore::prelude::*;
+/// use apexcharts_rs::prelude::{ChartSeries, ChartType, SeriesData};
+/// use apexcharts_rs::sycamore::ApexChartComponent;
+///
+/// #[component]
+/// fn App() -> View {
+///     let series = create_signal(vec![
+///         ChartSeries {
+///             name: "Series 1".to_string(),
+///             data: SeriesData::Single(vec![10, 20, 30, 40, 50]),
+///             color: "#008FFB".to_string(),
+///             r#type: None,
+///             z_index: None,
+///         }
+///     ]);
+///     view! {
+///         ApexChartComponent(id="my_area_chart", r#type=ChartType::Area, series=series, height="300px")
+///     }
+/// }
+///
+/// fn main() {
+///     sycamore::render(App);
+/// }
+/// ```
+#[component]
+pub fn ApexChartComponent(props: ApexChartComponentProps) -> View {
+	let ApexChartComponentProps { r#type, options, id, series, no_data_text, width, height, fallback, on_error, on_ready } = props;
+	let id = id.unwrap_or_else(generate_id);
+	let node_ref = create_node_ref();
+	let error = create_signal(None::<ApexChartsError>);
+	let chart = Rc::new(RefCell::new(None::<ApexChart>));
+
+	let chart_id = id.clone();
+	let mounted_chart = chart.clone();
+	on_mount(move || {
+		register_id(&chart_id);
+		let mut previous = None::<ChartConfig>;
+		create_effect(move || {
+			let config = ChartConfig {
+				id: Some(chart_id.clone()),
+				r#type: r#type.get_clone(),
+				series: series.get_clone(),
+				options: options.get_clone().into_owned(),
+				width: width.get_clone().into_owned(),
+				height: height.get_clone().into_owned(),
+				no_data_text: no_data_text.get_clone().map(Cow::into_owned),
+			};
+			let Some(element) = node_ref.try_get().and_then(|node| node.dyn_into::<HtmlElement>().ok()) else {
+				return;
+			};
+			let mut chart = mounted_chart.borrow_mut();
+			let current = chart.clone();
+			let result = draw(&mut chart, &config, previous.as_ref(), &element);
+			match &result {
+				Ok(()) => previous = Some(config),
+				Err(chart_error) => {
+					if let Some(chart) = chart.take() {
+						chart.destroy();
+					}
+					previous = None;
+					if let Some(on_error) = &on_error {
+						on_error(chart_error.clone());
+					}
+				},
+			}
+			if let (Some(on_ready), Some(new_chart), Some(config)) = (&on_ready, chart.as_ref(), previous.as_ref()) {
+				if current.as_ref() != Some(new_chart) {
+					on_ready(ChartHandle::new(new_chart.clone(), config.r#type.clone()));
+				}
+			}
+			error.set(result.err());
+		});
+	});
+	let unmounted_id = id.clone();
+	on_cleanup(move || {
+		if let Some(chart) = chart.borrow_mut().take() {
+			chart.destroy();
+		}
+		unregister_id(&unmounted_id);
+	});
+
+	view! {
+		div(id=id, r#ref=node_ref) {
+			(move || error.get_clone().map(|error| match &fallback {
+				Some(fallback) => fallback(),
+				None => view! {
+					div(class="apexcharts-error") { (error.to_string()) }
+				},
+			}))
+		}
+	}
+}
@@ -1,11 +1,15 @@
 //! This module contains the Yew component for rendering ApexCharts in a Yew application.
 
-use wasm_bindgen::JsValue;
-use gloo::timers::callback::Timeout;
-use serde_json::Value;
+use std::cell::{Cell, RefCell};
+use std::fmt::{Debug, Display};
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use web_sys::HtmlElement;
 use yew::prelude::*;
-use yew::props;
-use crate::prelude::{to_jsvalue, ApexChart, ChartSeries, ChartType, SeriesData};
+use crate::handle::{draw, render_chart};
+use crate::ids::{self, register_id, unregister_id, ID_PREFIX};
+use crate::prelude::{try_to_jsvalue, ApexChart, ApexChartsError, ChartConfig, ChartHandle, ChartSeries, ChartType};
 
 /// An ApexCharts component for Yew.
 /// 
@@ -15,22 +19,22 @@ use crate::prelude::{to_jsvalue, ApexChart, ChartSeries, ChartType, SeriesData};
 /// 
 /// 
 /// # Example
-/// 
-/// ```rust,ignore
+///
+/// ```rust,no_run
 /// use yew::prelude::*;
-/// use apexcharts_rs::prelude::{ApexChartComponent, ApexChartComponentProps, ChartSeries, SeriesData, ChartType};
-/// 
-/// 
+/// use apexcharts_rs::prelude::{ChartSeries, SeriesData, ChartType};
+/// use apexcharts_rs::yew::ApexChartComponent;
+///
 /// pub struct App;
-/// 
+///
 /// impl Component for App {
 ///     type Message = ();
 ///     type Properties = ();
-/// 
-///     fn create(ctx: &Context<Self>) -> Self {
+///
+///     fn create(_ctx: &Context<Self>) -> Self {
 ///         Self {}
 ///     }
-///     
+///
 ///     fn view(&self, _ctx: &Context<Self>) -> Html {
 ///         let series = vec![
 ///             ChartSeries {
@@ -49,28 +53,178 @@ use crate::prelude::{to_jsvalue, ApexChart, ChartSeries, ChartType, SeriesData};
 ///             }
 ///         ];
 ///         html! {
-///             <>
-///                 <ApexChartComponent
-///                    id={"my_area_chart".to_string()}
-///                    r#type={ChartType::Area}
-///                    series={series}
-///                    width={"100%".to_string()}
-///                    height={"300px".to_string()}
-///                  />
-///             </>
+///             <ApexChartComponent
+///                 id={"my_area_chart".to_string()}
+///                 r#type={ChartType::Area}
+///                 series={series}
+///                 width={"100%".to_string()}
+///                 height={"300px".to_string()}
+///             />
 ///         }
 ///     }
 /// }
-/// 
+///
+/// fn main() {
+///     yew::Renderer::<App>::new().render();
+/// }
+/// ```
 pub struct ApexChartComponent {
-	options: String,
-	chart: ApexChart,
-	_draw_timeout: Timeout,
+	/// The id of the chart, either provided in the properties or generated.
+	id: String,
+	chart: Option<ApexChart>,
+	error: Option<ApexChartsError>,
+	/// The serialized options of the chart to be created and rendered in its element after the next render.
+	pending_options: Option<String>,
+	node_ref: NodeRef,
+	/// The series returned by the loader, if any.
+	loaded_series: Option<Vec<ChartSeries>>,
+	/// The number of the current load. Results of previous loads are ignored.
+	load_count: usize,
+	loading: bool,
+	/// The callback registered in the loader to retry loading the series.
+	retry: Callback<()>,
 }
 
 pub enum ApexChartComponentMsg {
-	DrawChart,
-	OptionsUpdated(String)
+	/// The chart could not be rendered.
+	Failed(ApexChartsError),
+	/// Load the series from the loader.
+	Load,
+	/// The series of the load with the given number have been loaded.
+	Loaded(usize, Result<Vec<ChartSeries>, String>),
+}
+
+type SeriesFuture = Pin<Box<dyn Future<Output = Result<Vec<ChartSeries>, String>>>>;
+
+/// An asynchronous source of the series of a chart.
+///
+/// When a loader is passed to the [ApexChartComponent], the component displays a loading indicator
+/// while the series are loaded and the fallback if they fail to load. The series are loaded again when
+/// [SeriesLoader::retry] is called or when the component receives a different loader.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use yew::prelude::*;
+/// use apexcharts_rs::prelude::{ChartSeries, ChartType};
+/// use apexcharts_rs::yew::{ApexChartComponent, SeriesLoader};
+///
+/// async fn fetch_sales() -> Result<Vec<ChartSeries>, String> {
+///     // Fetch the series from the server, eg. with `gloo-net`.
+///     Ok(Vec::new())
+/// }
+///
+/// #[function_component]
+/// fn SalesChart() -> Html {
+///     let loader = use_memo((), |_| SeriesLoader::new(fetch_sales));
+///     html! {
+///         <ApexChartComponent r#type={ChartType::Bar} loader={(*loader).clone()} />
+///     }
+/// }
+/// ```
+#[derive(Clone)]
+pub struct SeriesLoader {
+	load: Rc<dyn Fn() -> SeriesFuture>,
+	retry: Rc<RefCell<Vec<Callback<()>>>>,
+}
+
+impl SeriesLoader {
+	/// Creates a loader from a function returning a future that resolves to the series of the chart.
+	pub fn new<F, Fut, E>(load: F) -> Self
+		where
+			F: Fn() -> Fut + 'static,
+			Fut: Future<Output = Result<Vec<ChartSeries>, E>> + 'static,
+			E: Display,
+	{
+		Self {
+			load: Rc::new(move || {
+				let future = load();
+				Box::pin(async move { future.await.map_err(|error| error.to_string()) })
+			}),
+			retry: Rc::default(),
+		}
+	}
+
+	/// Loads the series again in all the components using this loader.
+	pub fn retry(&self) {
+		let callbacks = self.retry.borrow().clone();
+		for callback in callbacks {
+			callback.emit(());
+		}
+	}
+}
+
+impl PartialEq for SeriesLoader {
+	fn eq(&self, other: &Self) -> bool {
+		Rc::ptr_eq(&self.load, &other.load)
+	}
+}
+
+impl Debug for SeriesLoader {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("SeriesLoader").finish_non_exhaustive()
+	}
+}
+
+/// Generates the ids of the charts rendered without an `id` property, counting from the start of each render of
+/// the application.
+///
+/// When the application is rendered on the server and hydrated in the browser, the generated ids must be the same on
+/// both sides. The ids are otherwise counted over the lifetime of the process, which differs between a long running
+/// server and the browser. Wrap the application in a [ChartIdsProvider], or provide a new `ChartIds` context for each
+/// render, so that the charts are given the same ids on the server and in the browser.
+#[derive(Clone, Debug, Default)]
+pub struct ChartIds(Rc<Cell<usize>>);
+
+impl ChartIds {
+	/// Returns the next id.
+	fn next_id(&self) -> String {
+		let id = self.0.get();
+		self.0.set(id + 1);
+		format!("{}h{}", ID_PREFIX, id)
+	}
+}
+
+impl PartialEq for ChartIds {
+	fn eq(&self, other: &Self) -> bool {
+		Rc::ptr_eq(&self.0, &other.0)
+	}
+}
+
+/// Provides new [ChartIds] to the charts of its children for each render of the application.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use yew::prelude::*;
+/// use apexcharts_rs::prelude::ChartType;
+/// use apexcharts_rs::yew::{ApexChartComponent, ChartIdsProvider};
+///
+/// #[function_component]
+/// fn App() -> Html {
+///     html! {
+///         <ChartIdsProvider>
+///             <ApexChartComponent r#type={ChartType::Bar} />
+///         </ChartIdsProvider>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn ChartIdsProvider(props: &yew::html::ChildrenProps) -> Html {
+	let ids = use_memo((), |_| ChartIds::default());
+	html! {
+		<ContextProvider<ChartIds> context={(*ids).clone()}>
+			{props.children.clone()}
+		</ContextProvider<ChartIds>>
+	}
+}
+
+/// Generates the id of a chart, from the [ChartIds] of the application if they are provided.
+fn generate_id(ctx: &Context<ApexChartComponent>) -> String {
+	match ctx.link().context::<ChartIds>(Callback::noop()) {
+		Some((ids, _)) => ids.next_id(),
+		None => ids::generate_id(),
+	}
 }
 
 #[derive(Clone, Debug, Properties, PartialEq)]
@@ -82,16 +236,42 @@ pub struct ApexChartComponentProps {
 	/// [ApexCharts documentation](https://apexcharts.com/docs/options/) for more information.
 	#[prop_or("".into())]
 	pub options: String,
-	/// The unique id of the chart. This is used to identify the chart in the DOM.
-	pub id: String,
+	/// The unique id of the chart. This is used to identify the chart in the DOM and is set as the `chart.id`
+	/// option of ApexCharts. A unique id is generated when it is not provided. When rendering on the server, either
+	/// provide the id or wrap the application in a [ChartIdsProvider] so that the generated id matches in the browser.
+	#[prop_or_default]
+	pub id: Option<String>,
 	/// The series to be rendered in the chart. This is used to set the data that will be rendered in the chart.
+	/// It is ignored when a `loader` is provided.
+	#[prop_or_default]
 	pub series: Vec<ChartSeries>,
+	/// Loads the series of the chart asynchronously.
+	#[prop_or_default]
+	pub loader: Option<SeriesLoader>,
+	/// The content rendered while the series are being loaded. Defaults to a loading message.
+	#[prop_or_default]
+	pub loading: Option<Html>,
+	/// The text displayed in place of the chart when the series are empty. Defaults to `No data` when the
+	/// series are provided by a `loader`.
+	#[prop_or_default]
+	pub no_data_text: Option<String>,
 	/// The width of the chart. This is used to set the width of the chart.
 	#[prop_or("100%".to_string())]
 	pub width: String,
 	/// The height of the chart. This is used to set the height of the chart.
 	#[prop_or("auto".to_string())]
 	pub height: String,
+	/// The content rendered in place of the chart when the options or series are not valid. Defaults
+	/// to a message describing the error.
+	#[prop_or_default]
+	pub fallback: Option<Html>,
+	/// Called with the error when the chart could not be built or rendered.
+	#[prop_or_default]
+	pub on_error: Callback<ApexChartsError>,
+	/// Called with a handle to the chart once it has been rendered, and again whenever the chart is recreated.
+	/// The handle is used to control the chart from the parent component.
+	#[prop_or_default]
+	pub on_ready: Callback<ChartHandle>,
 }
 
 impl Component for ApexChartComponent {
@@ -99,109 +279,426 @@ impl Component for ApexChartComponent {
 	type Properties = ApexChartComponentProps;
 
 	fn create(ctx: &Context<Self>) -> Self {
-		let link = ctx.link().clone();
-		let props = ctx.props().clone();
-		let stand_alone_timer = {
-			let link = link.clone();
-			Timeout::new(10, move||{
-				link.send_message(ApexChartComponentMsg::DrawChart);
-			})
+		// The component does not interact with the browser until it has been rendered, so that it can be rendered
+		// on the server.
+		let mut component = Self {
+			id: ctx.props().id.clone().unwrap_or_else(|| generate_id(ctx)),
+			chart: None,
+			error: None,
+			pending_options: None,
+			node_ref: NodeRef::default(),
+			loaded_series: None,
+			load_count: 0,
+			loading: false,
+			retry: ctx.link().callback(|_| ApexChartComponentMsg::Load),
 		};
-		let mut labels_data = None;
-		let series_data = match props.r#type {
-			ChartType::Pie | ChartType::Donut | ChartType::RadialBar => {
-				match props.series.first()
-				{
-					Some(chart_serie) => {
-						match chart_serie.data {
-							SeriesData::Radial(ref data) => {
-								let data_values = data.iter().map(|(_, y)| *y).collect::<Vec<_>>();
-								labels_data = Some(data.iter().map(|(x, _)| x.clone()).collect::<Vec<_>>());
-								serde_json::to_value(data_values).unwrap_or_default()
-							},
-							_=> {
-								serde_json::to_value(&props.series).unwrap_or_default()
-							}
-						}
-					},
-					_=> {
-						Value::Array(vec![])
-					}
-				}
-				
+		match &ctx.props().loader {
+			Some(loader) => {
+				loader.retry.borrow_mut().push(component.retry.clone());
+				component.loading = true;
 			},
-			_=> {
-				serde_json::to_value(&props.series).unwrap_or_default()
-			}
-		};
-		let options = if props.options.is_empty() {
-			format!(
-				r#"{{
-				"chart": {{
-					"type": "{}",
-					"width": "{}",
-					"height": "{}"
-				}},
-				"series": {}
-				{}
-			}}"#,
-				props.r#type,
-				props.width,
-				props.height,
-				series_data,
-				if let Some(labels) = labels_data {
-					format!(r#","labels": {}"#, serde_json::to_string(&labels).unwrap())
-				} else {
-					"".to_string()
-				}
-			)
-		} else {
-			let mut options = serde_json::from_str::<Value>(&props.options).unwrap();
-			options["chart"]["type"] = Value::String(props.r#type.to_string());
-			options["chart"]["width"] = Value::String(props.width.clone());
-			options["chart"]["height"] = Value::String(props.height.clone());
-			options["series"] = series_data;
-			if let Some(labels) = labels_data {
-				options["labels"] = Value::Array(labels.iter().map(|label| Value::String(label.clone())).collect());
-			}
-			serde_json::to_string(&options).unwrap_or_default()
-		};
-		link.send_message(ApexChartComponentMsg::OptionsUpdated(options.clone()));
-		Self {
-			chart: ApexChart::new(&JsValue::from_str(&options)),
-			_draw_timeout: stand_alone_timer,
-			options,
+			None => component.create_chart(ctx),
 		}
+		component
 	}
 
 	fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
 		match msg {
-			ApexChartComponentMsg::DrawChart => {
-				self.chart.render(&ctx.props().id.clone());
+			ApexChartComponentMsg::Failed(error) => {
+				self.fail(ctx, error);
 				true
 			}
-			ApexChartComponentMsg::OptionsUpdated(options) => {
-				self.options = options;
+			ApexChartComponentMsg::Load => {
+				self.load(ctx);
+				true
+			}
+			ApexChartComponentMsg::Loaded(load_count, result) => {
+				if load_count != self.load_count {
+					return false;
+				}
+				self.loading = false;
+				match result {
+					Ok(series) => {
+						self.loaded_series = Some(series);
+						self.create_chart(ctx);
+					},
+					Err(error) => self.fail(ctx, ApexChartsError::LoadFailed(error)),
+				}
 				true
 			}
 		}
 	}
 
-
+	fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+		let props = ctx.props();
+		if props.id != old_props.id {
+			unregister_id(&self.id);
+			self.id = props.id.clone().unwrap_or_else(|| generate_id(ctx));
+			register_id(&self.id);
+		}
+		if props.loader != old_props.loader {
+			if let Some(loader) = &old_props.loader {
+				loader.retry.borrow_mut().retain(|retry| retry != &self.retry);
+			}
+			self.loaded_series = None;
+			match &props.loader {
+				Some(loader) => {
+					loader.retry.borrow_mut().push(self.retry.clone());
+					self.load(ctx);
+				},
+				None => self.create_chart(ctx),
+			}
+			return true;
+		}
+		if self.is_waiting_for_series() {
+			return true;
+		}
+		// The chart is recreated when it cannot be updated in place: the type of a rendered chart cannot be changed,
+		// `updateOptions` merges the options and cannot remove one, and a chart that has not been rendered yet
+		// ignores updates.
+		if props.r#type != old_props.r#type
+			|| props.id != old_props.id
+			|| props.options != old_props.options
+			|| props.no_data_text != old_props.no_data_text
+			|| self.chart.is_none()
+			|| self.pending_options.is_some() {
+			self.create_chart(ctx);
+		} else if let Err(error) = self.update_chart(ctx, old_props) {
+			self.fail(ctx, error);
+		}
+		true
+	}
 
 	fn view(&self, ctx: &Context<Self>) -> Html {
+		if self.loading {
+			return html! {
+				<div id={self.id.clone()} ref={self.node_ref.clone()}>
+					{
+						match &ctx.props().loading {
+							Some(loading) => loading.clone(),
+							None => html! { <div class="apexcharts-loading">{"Loading..."}</div> },
+						}
+					}
+				</div>
+			};
+		}
+		if let Some(error) = &self.error {
+			let retry = match error {
+				ApexChartsError::LoadFailed(_) => html! {
+					<button class="apexcharts-retry" onclick={ctx.link().callback(|_| ApexChartComponentMsg::Load)}>{"Retry"}</button>
+				},
+				_ => html! {},
+			};
+			return html! {
+				<div id={self.id.clone()} ref={self.node_ref.clone()}>
+					{
+						match &ctx.props().fallback {
+							Some(fallback) => fallback.clone(),
+							None => html! { <div class="apexcharts-error">{error.to_string()}{retry}</div> },
+						}
+					}
+				</div>
+			};
+		}
+		// The size of the chart is reserved before it is rendered so that the page does not jump when it appears. On the
+		// server, the serialized options of the chart are included for the scripts of the page.
+		#[cfg(feature = "ssr")]
+		let options = self.pending_options.clone();
+		#[cfg(not(feature = "ssr"))]
+		let options = None::<String>;
+		let props = ctx.props();
 		html! {
-            <div id={ctx.props().id.clone()}></div>
-        }
+			<div
+				id={self.id.clone()}
+				ref={self.node_ref.clone()}
+				style={format!("width:{};min-height:{};", props.width, props.height)}
+				data-apexcharts-options={options}
+			></div>
+		}
 	}
 
 	fn rendered(&mut self, ctx: &Context<Self>, first_render: bool) {
-		if !first_render {
-			if ctx.props().r#type == ChartType::Pie || ctx.props().r#type == ChartType::Donut || ctx.props().r#type == ChartType::RadialBar {
-				self.chart.update_options(&JsValue::from_str(&self.options), Some(true), Some(false), Some(true));
+		if first_render {
+			register_id(&self.id);
+			if ctx.props().loader.is_some() {
+				self.load(ctx);
+			}
+		}
+		// The chart configured in `create` is drawn on the first render, subsequent renders draw the charts
+		// recreated in `changed`.
+		if let Err(error) = self.render_chart(ctx) {
+			ctx.link().send_message(ApexChartComponentMsg::Failed(error));
+		}
+	}
+
+	fn destroy(&mut self, ctx: &Context<Self>) {
+		if let Some(chart) = self.chart.take() {
+			chart.destroy();
+		}
+		if let Some(loader) = &ctx.props().loader {
+			loader.retry.borrow_mut().retain(|retry| retry != &self.retry);
+		}
+		unregister_id(&self.id);
+	}
+}
+
+impl ApexChartComponent {
+	/// Prepares a new chart from the properties of the component, destroying the existing chart if any.
+	/// The chart is created and rendered the next time the component is rendered.
+	fn create_chart(&mut self, ctx: &Context<Self>) {
+		if let Some(chart) = self.chart.take() {
+			chart.destroy();
+		}
+		self.error = None;
+		self.pending_options = None;
+		match self.config(ctx).to_json() {
+			Ok(options) => self.pending_options = Some(options),
+			Err(error) => self.fail(ctx, error),
+		}
+	}
+
+	/// Destroys the chart and loads the series from the loader. The chart is created once the series are loaded.
+	fn load(&mut self, ctx: &Context<Self>) {
+		let Some(loader) = &ctx.props().loader else {
+			return;
+		};
+		if let Some(chart) = self.chart.take() {
+			chart.destroy();
+		}
+		self.error = None;
+		self.pending_options = None;
+		self.loading = true;
+		self.load_count += 1;
+		let load_count = self.load_count;
+		let future = (loader.load)();
+		let link = ctx.link().clone();
+		wasm_bindgen_futures::spawn_local(async move {
+			link.send_message(ApexChartComponentMsg::Loaded(load_count, future.await));
+		});
+	}
+
+	/// Creates the chart and renders it in its element if it has not been rendered yet.
+	fn render_chart(&mut self, ctx: &Context<Self>) -> Result<(), ApexChartsError> {
+		let Some(element) = self.node_ref.cast::<HtmlElement>() else {
+			return Ok(());
+		};
+		let Some(options) = self.pending_options.take() else {
+			return Ok(());
+		};
+		let chart = render_chart(&options, &element)?;
+		self.chart = Some(chart.clone());
+		ctx.props().on_ready.emit(ChartHandle::new(chart, ctx.props().r#type.clone()));
+		Ok(())
+	}
+
+	/// Applies the changes in the size or series to the rendered chart.
+	fn update_chart(&self, ctx: &Context<Self>, old_props: &ApexChartComponentProps) -> Result<(), ApexChartsError> {
+		let Some(chart) = &self.chart else {
+			return Ok(());
+		};
+		let props = ctx.props();
+		let config = self.config(ctx);
+		if props.width != old_props.width || props.height != old_props.height {
+			chart.update_options(&config.to_jsvalue()?, None, None, None);
+		} else if props.series != old_props.series && props.loader.is_none() {
+			if config.is_radial() {
+				chart.update_options(&config.to_jsvalue()?, Some(true), Some(false), Some(true));
 			} else {
-				self.chart.update_series(&to_jsvalue(ctx.props().series.clone()), Some(false));
+				chart.update_series(&try_to_jsvalue(&props.series)?, Some(false));
 			}
 		}
+		Ok(())
+	}
+
+	/// Returns whether the chart waits for the series of the loader: while they are loading, and after they failed to
+	/// load until the loader is retried, as the chart would otherwise be drawn without the series.
+	fn is_waiting_for_series(&self) -> bool {
+		self.loading || matches!(self.error, Some(ApexChartsError::LoadFailed(_)))
+	}
+
+	/// Returns the configuration of the chart from the properties of the component.
+	fn config(&self, ctx: &Context<Self>) -> ChartConfig {
+		let props = ctx.props();
+		let no_data_text = match &props.loader {
+			Some(_) => props.no_data_text.clone().or_else(|| Some("No data".to_string())),
+			None => props.no_data_text.clone(),
+		};
+		ChartConfig {
+			id: Some(self.id.clone()),
+			r#type: props.r#type.clone(),
+			series: self.loaded_series.clone().unwrap_or_else(|| props.series.clone()),
+			options: props.options.clone(),
+			width: props.width.clone(),
+			height: props.height.clone(),
+			no_data_text,
+		}
+	}
+
+	/// Destroys the chart and reports the error, rendering the fallback in place of the chart.
+	fn fail(&mut self, ctx: &Context<Self>, error: ApexChartsError) {
+		if let Some(chart) = self.chart.take() {
+			chart.destroy();
+		}
+		ctx.props().on_error.emit(error.clone());
+		self.error = Some(error);
+	}
+}
+
+/// Renders a chart into the element of the node ref and returns a handle to control it.
+///
+/// The chart is created once the element has been mounted, updated in place when its series or size change
+/// and destroyed when the component using the hook is unmounted. The chart is recreated when its type, id, options
+/// or `noData` text change. The hook returns `None` until the chart has been rendered, and when the configuration is
+/// not valid, in which case the error is logged to the browser console. Use [ChartConfig::to_value] to validate the
+/// configuration beforehand.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use yew::prelude::*;
+/// use apexcharts_rs::prelude::{ChartConfig, ChartSeries, ChartType};
+/// use apexcharts_rs::yew::use_apex_chart;
+///
+/// #[derive(PartialEq, Properties)]
+/// struct SalesChartProps {
+///     series: Vec<ChartSeries>,
+/// }
+///
+/// #[function_component]
+/// fn SalesChart(props: &SalesChartProps) -> Html {
+///     let node_ref = use_node_ref();
+///     let chart = use_apex_chart(node_ref.clone(), ChartConfig::new(ChartType::Bar, props.series.clone()));
+///     let reset = Callback::from(move |_| if let Some(chart) = &chart { let _ = chart.reset(); });
+///     html! {
+///         <>
+///             <div ref={node_ref}></div>
+///             <button onclick={reset}>{"Reset"}</button>
+///         </>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_apex_chart(node_ref: NodeRef, config: ChartConfig) -> Option<ChartHandle> {
+	let handle = use_state_eq(|| None::<ChartHandle>);
+	let chart = use_mut_ref(|| None::<ApexChart>);
+	// The element and the configuration the chart was last drawn with.
+	let drawn = use_mut_ref(|| None::<(HtmlElement, ChartConfig)>);
+	{
+		let handle = handle.setter();
+		let chart = chart.clone();
+		// The effect runs after every render rather than when the configuration changes, so that the chart is drawn
+		// once the element of the node ref is mounted, eg. when it is rendered conditionally or attached later.
+		use_effect(move || {
+			let Some(element) = node_ref.cast::<HtmlElement>() else {
+				return;
+			};
+			let previous = match drawn.replace(Some((element.clone(), config.clone()))) {
+				Some((drawn_element, previous)) if drawn_element == element => match previous == config {
+					true => return,
+					false => Some(previous),
+				},
+				// The chart is rendered again when the node ref has been attached to another element.
+				_ => None,
+			};
+			let mut chart = chart.borrow_mut();
+			if let Err(error) = draw(&mut chart, &config, previous.as_ref(), &element) {
+				web_sys::console::error_1(&format!("apexcharts-rs: {}", error).into());
+			}
+			handle.set(chart.clone().map(|chart| ChartHandle::new(chart, config.r#type.clone())));
+		});
+	}
+	use_effect_with((), move |_| {
+		move || {
+			if let Some(chart) = chart.borrow_mut().take() {
+				chart.destroy();
+			}
+		}
+	});
+	(*handle).clone()
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+	use yew::prelude::*;
+	use yew::LocalServerRenderer;
+	use crate::prelude::{ApexChartsError, ChartConfig, ChartSeries, ChartType, SeriesData};
+	use crate::yew::{ApexChartComponent, ApexChartComponentProps, ChartIdsProvider};
+
+	fn render(props: ApexChartComponentProps) -> String {
+		futures::executor::block_on(LocalServerRenderer::<ApexChartComponent>::with_props(props).hydratable(false).render())
+	}
+
+	#[test]
+	pub fn test_server_placeholder() {
+		let series = vec![
+			ChartSeries {
+				name: "Series 1".to_string(),
+				data: SeriesData::Single(vec![10, 20]),
+				color: "#008FFB".to_string(),
+				r#type: None,
+				z_index: None,
+			}
+		];
+		let html = render(yew::props!(ApexChartComponentProps {
+			id: Some("sales".to_string()),
+			r#type: ChartType::Bar,
+			series: series.clone(),
+			height: "300px".to_string(),
+		}));
+		let options = ChartConfig {
+			id: Some("sales".to_string()),
+			height: "300px".to_string(),
+			..ChartConfig::new(ChartType::Bar, series)
+		}.to_json().unwrap();
+		assert!(html.contains(r#"style="width:100%;min-height:300px;""#), "{}", html);
+		assert!(html.contains(&format!(r#"data-apexcharts-options="{}""#, options.replace('"', "&quot;"))), "{}", html);
+	}
+
+	#[test]
+	pub fn test_server_fallback() {
+		let html = render(yew::props!(ApexChartComponentProps {
+			options: "{".to_string(),
+		}));
+		let error = ApexChartsError::InvalidOptions("EOF while parsing an object at line 1 column 1".to_string());
+		assert!(html.contains(&format!(r#"<div class="apexcharts-error">{}</div>"#, error)), "{}", html);
+	}
+
+	#[test]
+	pub fn test_waiting_for_series() {
+		let component = |loading: bool, error: Option<ApexChartsError>| ApexChartComponent {
+			id: "sales".to_string(),
+			chart: None,
+			error,
+			pending_options: None,
+			node_ref: NodeRef::default(),
+			loaded_series: None,
+			load_count: 1,
+			loading,
+			retry: Callback::noop(),
+		};
+		assert!(component(true, None).is_waiting_for_series());
+		// A change of the properties after the series failed to load keeps the error and its retry button.
+		assert!(component(false, Some(ApexChartsError::LoadFailed("timeout".to_string()))).is_waiting_for_series());
+		assert!(!component(false, Some(ApexChartsError::InvalidOptions("expected a JSON object".to_string()))).is_waiting_for_series());
+		assert!(!component(false, None).is_waiting_for_series());
+	}
+
+	#[function_component]
+	fn App() -> Html {
+		html! {
+			<ChartIdsProvider>
+				<ApexChartComponent r#type={ChartType::Bar} />
+				<ApexChartComponent r#type={ChartType::Line} />
+			</ChartIdsProvider>
+		}
+	}
+
+	#[test]
+	pub fn test_server_ids() {
+		let render = || futures::executor::block_on(LocalServerRenderer::<App>::new().hydratable(false).render());
+		let html = render();
+		assert!(html.contains(r#"id="apexchart-h0""#) && html.contains(r#"id="apexchart-h1""#), "{}", html);
+		// The ids are counted from the start of each render, as they are when hydrating in the browser.
+		assert_eq!(render(), html);
 	}
 }
@@ -0,0 +1,91 @@
+//! Checks that the prelude can be glob imported alongside the preludes of the UI frameworks.
+
+#[cfg(feature = "yew")]
+mod yew_app {
+	use apexcharts_rs::prelude::*;
+	use yew::prelude::*;
+
+	#[function_component]
+	pub fn App() -> Html {
+		let series = vec![
+			ChartSeries {
+				name: "Series 1".to_string(),
+				data: SeriesData::Single(vec![10, 20]),
+				color: "#008FFB".to_string(),
+				r#type: None,
+				z_index: None,
+			}
+		];
+		html! {
+			<apexcharts_rs::yew::ApexChartComponent r#type={ChartType::Bar} series={series} />
+		}
+	}
+
+	#[test]
+	pub fn test_yew_prelude() {
+		let _ = html! { <App /> };
+	}
+}
+
+#[cfg(feature = "leptos")]
+mod leptos_app {
+	use apexcharts_rs::prelude::*;
+	use leptos::prelude::*;
+
+	#[component]
+	pub fn App() -> impl IntoView {
+		let series = vec![
+			ChartSeries {
+				name: "Series 1".to_string(),
+				data: SeriesData::Single(vec![10, 20]),
+				color: "#008FFB".to_string(),
+				r#type: None,
+				z_index: None,
+			}
+		];
+		view! {
+			<apexcharts_rs::leptos::ApexChartComponent r#type=ChartType::Bar series=series />
+		}
+	}
+
+	#[test]
+	pub fn test_leptos_prelude() {
+		let _: fn() -> _ = App;
+	}
+}
+
+#[cfg(feature = "dioxus")]
+mod dioxus_app {
+	use apexcharts_rs::prelude::*;
+	use dioxus::prelude::*;
+
+	#[component]
+	pub fn App() -> Element {
+		rsx! {
+			apexcharts_rs::dioxus::ApexChartComponent { r#type: ChartType::Bar, series: Vec::<ChartSeries>::new() }
+		}
+	}
+
+	#[test]
+	pub fn test_dioxus_prelude() {
+		let _: fn() -> Element = App;
+	}
+}
+
+#[cfg(feature = "sycamore")]
+mod sycamore_app {
+	use apexcharts_rs::prelude::*;
+	use sycamore::prelude::*;
+
+	#[component]
+	pub fn App() -> View {
+		view! {
+			apexcharts_rs::sycamore::ApexChartComponent(r#type=ChartType::Bar)
+		}
+	}
+
+	#[test]
+	pub fn test_sycamore_prelude() {
+		let _: fn() -> View = App;
+	}
+}